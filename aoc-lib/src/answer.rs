@@ -0,0 +1,97 @@
+//! Puzzle answers
+
+use json::JsonValue;
+use std::fmt;
+
+/// Answer to one part of a day's puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String)
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s)
+        }
+    }
+}
+
+impl Answer {
+    /// Converts the answer to a JSON number or string
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Answer::Signed(n) => (*n).into(),
+            Answer::Unsigned(n) => (*n).into(),
+            Answer::Text(s) => s.as_str().into()
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Answer to one part of a day's puzzle with extra detail about the solution
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// The answer
+    pub answer: Answer,
+    /// Named items of extra detail
+    pub detail: Vec<(&'static str, JsonValue)>
+}
+
+impl Solution {
+    /// Adds an item of extra detail
+    pub fn with_detail<V: Into<JsonValue>>(mut self, name: &'static str, value: V) -> Self {
+        self.detail.push((name, value.into()));
+        self
+    }
+}
+
+impl<T: Into<Answer>> From<T> for Solution {
+    fn from(value: T) -> Self {
+        Self {
+            answer: value.into(),
+            detail: Vec::new()
+        }
+    }
+}
+
+#[test]
+fn test_solution() {
+    let solution = Solution::from(12u8).with_detail("items", vec!["a", "b"]);
+
+    assert!(solution.answer == Answer::Unsigned(12));
+    assert!(solution.answer.to_json().dump() == "12");
+    assert!(solution.detail[0].0 == "items");
+    assert!(solution.detail[0].1.dump() == r#"["a","b"]"#);
+    assert!(Answer::from("abc").to_json().dump() == r#""abc""#);
+}
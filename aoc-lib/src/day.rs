@@ -0,0 +1,67 @@
+//! Puzzle day registration
+
+use crate::{input::InputSource, Input, InputError, Rng, Solution};
+use std::{error::Error, path::Path};
+
+/// Result of solving one part of a day's puzzle
+pub type PartResult = Result<Solution, Box<dyn Error>>;
+
+/// Solver function for one part of a day's puzzle
+pub type PartFn = fn(&Input) -> PartResult;
+
+/// Random input generator for a day's puzzle. The size scales the input, for
+/// example the number of lines or the length of a string
+pub type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// One part of a day's puzzle
+pub struct Part {
+    /// Part number
+    pub part: u8,
+    /// Description of the answer
+    pub desc: &'static str,
+    /// Solver function
+    pub solve: PartFn
+}
+
+/// A puzzle parameter which can be overridden on the command line
+pub struct Param {
+    /// Parameter name
+    pub name: &'static str,
+    /// Description of the parameter
+    pub desc: &'static str
+}
+
+/// A day's puzzle and its solvers
+pub struct Day {
+    /// Day number
+    pub day: u8,
+    /// Input file name, if the puzzle has one
+    pub input: Option<&'static str>,
+    /// Puzzle parts
+    pub parts: &'static [Part],
+    /// Parameters which can be overridden
+    pub params: &'static [Param],
+    /// Random input generator
+    pub generate: GenerateFn
+}
+
+impl Day {
+    /// Loads the input for the day from an explicit input argument ("-" for
+    /// standard input) or the default input file in the input directory
+    pub fn load_input(&self, input: Option<&str>, input_dir: Option<&Path>) -> Result<Input, InputError> {
+        match self.input {
+            Some(file) => InputSource::resolve(input, input_dir, file).load(),
+            None => Ok(Input::from(""))
+        }
+    }
+
+    /// Looks up a parameter of the day by name
+    pub fn param(&self, name: &str) -> Option<&Param> {
+        self.params.iter().find(|p| p.name == name)
+    }
+
+    /// Looks up a part of the day by number
+    pub fn part(&self, part: u8) -> Option<&Part> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+}
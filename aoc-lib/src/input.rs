@@ -0,0 +1,342 @@
+//! Puzzle input loading
+
+use crate::parse::Line;
+use std::{collections::HashMap, error::Error, fmt, fs::File, io::{self, Read}, path::{Path, PathBuf}, str::FromStr};
+
+/// Environment variable naming the default input directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin
+}
+
+impl InputSource {
+    /// Resolves an explicit input argument ("-" for standard input), falling back
+    /// to the default file name in the input directory or the current directory
+    pub fn resolve(input: Option<&str>, input_dir: Option<&Path>, default_file: &str) -> Self {
+        match input {
+            Some("-") => InputSource::Stdin,
+            Some(file) => InputSource::File(PathBuf::from(file)),
+            None => match input_dir {
+                Some(dir) => InputSource::File(dir.join(default_file)),
+                None => InputSource::File(PathBuf::from(default_file))
+            }
+        }
+    }
+
+    /// Loads the input from the source
+    pub fn load(&self) -> Result<Input, InputError> {
+        match self {
+            InputSource::File(file) => Input::from_file(file),
+            InputSource::Stdin => Input::from_reader(io::stdin().lock(), "<stdin>")
+        }
+    }
+}
+
+/// Puzzle input text and parameter overrides
+pub struct Input {
+    text: String,
+    params: HashMap<String, String>
+}
+
+impl Input {
+    /// Loads the input from a file, validating that it is UTF-8
+    pub fn from_file<P: AsRef<Path>>(file: P) -> Result<Self, InputError> {
+        let file = file.as_ref();
+        let name = file.display().to_string();
+
+        // Open the file
+        let reader = File::open(file).map_err(|error| InputError::Io {
+            name: name.clone(),
+            error
+        })?;
+
+        Self::from_reader(reader, &name)
+    }
+
+    /// Loads the input from a reader, validating that it is UTF-8
+    pub fn from_reader<R: Read>(mut reader: R, name: &str) -> Result<Self, InputError> {
+        // Read the content
+        let mut bytes = Vec::new();
+
+        reader.read_to_end(&mut bytes).map_err(|error| InputError::Io {
+            name: name.to_string(),
+            error
+        })?;
+
+        // Validate the content
+        let text = String::from_utf8(bytes).map_err(|e| InputError::Utf8 {
+            name: name.to_string(),
+            offset: e.utf8_error().valid_up_to()
+        })?;
+
+        Ok(Self::from(text))
+    }
+
+    /// Sets a parameter override
+    pub fn set_param(&mut self, name: &str, value: &str) {
+        self.params.insert(name.to_string(), value.to_string());
+    }
+
+    /// Returns the value of a parameter override, if one was given
+    pub fn param<T>(&self, name: &str) -> Result<Option<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display
+    {
+        match self.params.get(name) {
+            Some(value) => value.parse::<T>().map(Some).map_err(|e| InputError::Param {
+                name: name.to_string(),
+                value: value.clone(),
+                message: e.to_string()
+            }),
+            None => Ok(None)
+        }
+    }
+
+    /// Returns the value of a parameter override, or the default if not given
+    pub fn param_or<T>(&self, name: &str, default: T) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display
+    {
+        Ok(self.param(name)?.unwrap_or(default))
+    }
+
+    /// Returns the value of a parameter override, or parses the whole trimmed
+    /// input text for puzzles whose input is a single value
+    pub fn param_or_input<T>(&self, name: &str) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display
+    {
+        if let Some(value) = self.param(name)? {
+            return Ok(value)
+        }
+
+        let text = self.text.trim();
+
+        text.parse::<T>().map_err(|e| InputError::Parse {
+            line: 1,
+            text: text.to_string(),
+            message: e.to_string()
+        })
+    }
+
+    /// Returns the value of a parameter override, or parses the value of a
+    /// "Name: value" line in the input
+    pub fn param_or_property<T>(&self, name: &str, property: &str) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display
+    {
+        if let Some(value) = self.param(name)? {
+            return Ok(value)
+        }
+
+        for (idx, l) in self.text.lines().enumerate() {
+            if let Some((key, value)) = l.split_once(':') {
+                if key.trim() == property {
+                    return value.trim().parse::<T>().map_err(|e| InputError::Parse {
+                        line: idx + 1,
+                        text: l.to_string(),
+                        message: e.to_string()
+                    })
+                }
+            }
+        }
+
+        Err(InputError::Missing { name: property.to_string() })
+    }
+
+    /// Returns the whole input text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the non-empty lines of the input
+    pub fn lines(&self) -> Vec<&str> {
+        self.text.lines().filter(|l| !l.is_empty()).collect()
+    }
+
+    /// Returns the non-empty lines of the input with their line numbers
+    pub fn numbered_lines(&self) -> Vec<Line<'_>> {
+        self.text.lines().enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(idx, l)| Line::new(idx + 1, l))
+            .collect()
+    }
+
+    /// Parses each non-empty line of the input
+    pub fn parsed<T>(&self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display
+    {
+        self.text.lines().enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(idx, l)| {
+                l.parse::<T>().map_err(|e| InputError::Parse {
+                    line: idx + 1,
+                    text: l.to_string(),
+                    message: e.to_string()
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the blank line separated sections of the input
+    pub fn sections(&self) -> Vec<Vec<&str>> {
+        self.numbered_sections().into_iter()
+            .map(|section| section.into_iter().map(|l| l.text).collect())
+            .collect()
+    }
+
+    /// Returns the blank line separated sections of the input with their line
+    /// numbers
+    pub fn numbered_sections(&self) -> Vec<Vec<Line<'_>>> {
+        let mut sections = Vec::new();
+        let mut section = Vec::new();
+
+        for (idx, l) in self.text.lines().enumerate() {
+            if l.is_empty() {
+                if !section.is_empty() {
+                    sections.push(section);
+                    section = Vec::new();
+                }
+            } else {
+                section.push(Line::new(idx + 1, l));
+            }
+        }
+
+        if !section.is_empty() {
+            sections.push(section);
+        }
+
+        sections
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            params: HashMap::new()
+        }
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Self::from(text.to_string())
+    }
+}
+
+/// Input loading error
+#[derive(Debug)]
+pub enum InputError {
+    /// The input could not be read
+    Io { name: String, error: io::Error },
+    /// The input is not valid UTF-8
+    Utf8 { name: String, offset: usize },
+    /// A line of the input could not be parsed
+    Parse { line: usize, text: String, message: String },
+    /// A parameter override could not be parsed
+    Param { name: String, value: String, message: String },
+    /// A required value is not present in the input
+    Missing { name: String }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io { name, error } => write!(f, "{}: {}", name, error),
+            InputError::Utf8 { name, offset } => write!(f, "{}: invalid UTF-8 at byte {}", name, offset),
+            InputError::Parse { line, text, message } => write!(f, "line {}: {} ({})", line, message, text),
+            InputError::Param { name, value, message } => write!(f, "parameter {}={}: {}", name, value, message),
+            InputError::Missing { name } => write!(f, "{} not found in input", name)
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { error, .. } => Some(error),
+            _ => None
+        }
+    }
+}
+
+#[test]
+fn test_lines() {
+    let input = Input::from("abc\n\ndef\r\nghi\n");
+
+    assert!(input.lines() == vec!["abc", "def", "ghi"]);
+    assert!(input.numbered_lines() == vec![Line::new(1, "abc"), Line::new(3, "def"), Line::new(4, "ghi")]);
+}
+
+#[test]
+fn test_parsed() {
+    let input = Input::from("1\n2\n\n3\n");
+    assert!(input.parsed::<u16>().unwrap() == vec![1, 2, 3]);
+
+    let input = Input::from("1\n\nx\n");
+    match input.parsed::<u16>() {
+        Err(InputError::Parse { line, text, .. }) => {
+            assert!(line == 3);
+            assert!(text == "x");
+        }
+        _ => panic!("Expected parse error")
+    }
+}
+
+#[test]
+fn test_sections() {
+    let input = Input::from("a\nb\n\n\nc\n\n");
+
+    assert!(input.sections() == vec![vec!["a", "b"], vec!["c"]]);
+    assert!(input.numbered_sections()[1] == vec![Line::new(5, "c")]);
+}
+
+#[test]
+fn test_utf8() {
+    let result = Input::from_reader(&b"ab\xffcd"[..], "test");
+
+    assert!(matches!(result, Err(InputError::Utf8 { offset: 2, .. })));
+}
+
+#[test]
+fn test_params() {
+    let mut input = Input::from("");
+    input.set_param("size", "10");
+    input.set_param("name", "x");
+
+    assert!(input.param::<u32>("size").unwrap() == Some(10));
+    assert!(input.param_or::<u32>("other", 5).unwrap() == 5);
+    assert!(matches!(input.param::<u32>("name"), Err(InputError::Param { .. })));
+
+    let mut input = Input::from("  42\n");
+    assert!(input.param_or_input::<u32>("size").unwrap() == 42);
+    input.set_param("size", "7");
+    assert!(input.param_or_input::<u32>("size").unwrap() == 7);
+
+    let mut input = Input::from("Hit Points: 109\nDamage: 8\n");
+    input.set_param("damage", "9");
+    assert!(input.param_or_property::<u16>("hit_points", "Hit Points").unwrap() == 109);
+    assert!(input.param_or_property::<u16>("damage", "Damage").unwrap() == 9);
+    assert!(matches!(input.param_or_property::<u16>("armor", "Armor"), Err(InputError::Missing { .. })));
+}
+
+#[test]
+fn test_resolve() {
+    let dir = Path::new("inputs");
+
+    assert!(InputSource::resolve(Some("-"), Some(dir), "input01.txt") == InputSource::Stdin);
+    assert!(InputSource::resolve(Some("my.txt"), Some(dir), "input01.txt") == InputSource::File(PathBuf::from("my.txt")));
+    assert!(InputSource::resolve(None, Some(dir), "input01.txt") == InputSource::File(PathBuf::from("inputs/input01.txt")));
+    assert!(InputSource::resolve(None, None, "input01.txt") == InputSource::File(PathBuf::from("input01.txt")));
+}
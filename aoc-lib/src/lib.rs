@@ -0,0 +1,15 @@
+//! Common support code shared by the Advent of Code 2015 solutions
+
+pub mod answer;
+pub mod day;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod random;
+pub mod runner;
+
+pub use answer::{Answer, Solution};
+pub use day::{Day, GenerateFn, Param, Part, PartFn, PartResult};
+pub use input::{Input, InputError, InputSource};
+pub use parse::{InvalidBytes, Line, ParseError, Validation};
+pub use random::Rng;
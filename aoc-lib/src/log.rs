@@ -0,0 +1,96 @@
+//! Diagnostic output with selectable verbosity, written to standard error or a file
+
+use clap::ValueEnum;
+use std::{fmt, fs::File, io::{self, BufWriter, Write}, path::Path, sync::{atomic::{AtomicU8, Ordering}, Mutex}};
+
+/// Diagnostic verbosity level
+#[derive(ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Answers only
+    Quiet,
+    /// Answers with solution detail and informational messages
+    #[default]
+    Normal,
+    /// Everything, including every candidate considered by a search
+    Trace
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+static OUTPUT: Mutex<Option<BufWriter<File>>> = Mutex::new(None);
+
+/// Sets the verbosity level
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Returns the verbosity level
+pub fn verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        1 => Verbosity::Normal,
+        _ => Verbosity::Trace
+    }
+}
+
+/// Returns true if messages at the given level are written
+pub fn enabled(level: Verbosity) -> bool {
+    level != Verbosity::Quiet && verbosity() >= level
+}
+
+/// Directs diagnostic output to a file instead of standard error
+pub fn set_output_file<P: AsRef<Path>>(file: P) -> io::Result<()> {
+    let file = File::create(file)?;
+
+    *OUTPUT.lock().unwrap() = Some(BufWriter::new(file));
+
+    Ok(())
+}
+
+/// Writes a diagnostic message. Use the `info!` and `trace!` macros instead
+/// of calling this directly
+pub fn write(args: fmt::Arguments) {
+    match OUTPUT.lock().unwrap().as_mut() {
+        Some(file) => { let _ = writeln!(file, "{}", args); }
+        None => eprintln!("{}", args)
+    }
+}
+
+/// Flushes diagnostic output written to a file
+pub fn flush() {
+    if let Some(file) = OUTPUT.lock().unwrap().as_mut() {
+        let _ = file.flush();
+    }
+}
+
+/// Writes a diagnostic message at the normal verbosity level
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Normal) {
+            $crate::log::write(format_args!($($arg)*))
+        }
+    };
+}
+
+/// Writes a diagnostic message at the trace verbosity level
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Trace) {
+            $crate::log::write(format_args!($($arg)*))
+        }
+    };
+}
+
+#[test]
+fn test_enabled() {
+    set_verbosity(Verbosity::Quiet);
+    assert!(!enabled(Verbosity::Quiet) && !enabled(Verbosity::Normal) && !enabled(Verbosity::Trace));
+
+    set_verbosity(Verbosity::Trace);
+    assert!(enabled(Verbosity::Normal) && enabled(Verbosity::Trace));
+
+    set_verbosity(Verbosity::Normal);
+    assert!(enabled(Verbosity::Normal) && !enabled(Verbosity::Trace));
+    assert!(verbosity() == Verbosity::Normal);
+}
@@ -0,0 +1,191 @@
+//! Structured errors for puzzle input parsers
+
+use std::{ascii, error::Error, fmt, str::FromStr};
+
+/// Number of invalid bytes listed in an error message
+const MAX_LISTED: usize = 10;
+
+/// A numbered line of puzzle input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting at 1
+    pub number: usize,
+    /// Line text
+    pub text: &'a str
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// Returns the column (starting at 1) of a slice of this line's text, or 1
+    /// if the slice does not come from this line
+    pub fn column(&self, at: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let pos = at.as_ptr() as usize;
+
+        if pos >= start && pos + at.len() <= start + self.text.len() {
+            self.text[..pos - start].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// Builds an error for a slice of this line's text
+    pub fn error(&self, at: &str, expected: &str) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.column(at),
+            text: at.to_string(),
+            expected: expected.to_string()
+        }
+    }
+
+    /// Builds an error for the whole line
+    pub fn line_error(&self, expected: &str) -> ParseError {
+        self.error(self.text, expected)
+    }
+
+    /// Parses a slice of this line's text
+    pub fn parse<T: FromStr>(&self, at: &str, expected: &str) -> Result<T, ParseError> {
+        at.parse::<T>().map_err(|_| self.error(at, expected))
+    }
+}
+
+/// An error parsing a line of puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number in characters, starting at 1
+    pub column: usize,
+    /// The offending text
+    pub text: String,
+    /// Description of what was expected
+    pub expected: String
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, found \"{}\"", self.line, self.column, self.expected, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// How bytes which are not part of a single string of symbols are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Validation {
+    /// Every byte must be a symbol, apart from a line ending at the very end
+    Strict,
+    /// Whitespace is skipped anywhere, but every other byte must be a symbol
+    Lenient
+}
+
+impl Validation {
+    /// Checks every byte of the text, returning all of the invalid ones
+    pub fn check<F: Fn(u8) -> bool>(&self, text: &str, is_symbol: F, expected: &str) -> Result<(), InvalidBytes> {
+        let body = match self {
+            Validation::Strict => text.strip_suffix('\n').map_or(text, |t| t.strip_suffix('\r').unwrap_or(t)),
+            Validation::Lenient => text
+        };
+
+        let skipped = |b: u8| *self == Validation::Lenient && b.is_ascii_whitespace();
+
+        let invalid: Vec<InvalidByte> = body.bytes().enumerate()
+            .filter(|&(_, b)| !is_symbol(b) && !skipped(b))
+            .map(|(i, byte)| InvalidByte { pos: i + 1, byte })
+            .collect();
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(InvalidBytes { expected: expected.to_string(), invalid })
+        }
+    }
+}
+
+impl FromStr for Validation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(Validation::Strict),
+            "lenient" => Ok(Validation::Lenient),
+            _ => Err("expected strict or lenient".to_string())
+        }
+    }
+}
+
+/// A byte which is not a valid symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidByte {
+    /// Position in the input in bytes, starting at 1
+    pub pos: usize,
+    pub byte: u8
+}
+
+/// An error listing every invalid byte in a string of symbols
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidBytes {
+    /// Description of the valid symbols
+    pub expected: String,
+    pub invalid: Vec<InvalidByte>
+}
+
+impl fmt::Display for InvalidBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid bytes, expected {}:", self.invalid.len(), self.expected)?;
+
+        for b in self.invalid.iter().take(MAX_LISTED) {
+            write!(f, " '{}' at {}", ascii::escape_default(b.byte), b.pos)?;
+        }
+
+        if self.invalid.len() > MAX_LISTED {
+            write!(f, " and {} more", self.invalid.len() - MAX_LISTED)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for InvalidBytes {}
+
+#[test]
+fn test_line_error() {
+    let line = Line::new(3, "toggle 1,x through 5,5");
+
+    let num: Result<u16, _> = line.parse(&line.text[9..10], "a number");
+    assert!(num == Err(ParseError {
+        line: 3,
+        column: 10,
+        text: "x".to_string(),
+        expected: "a number".to_string()
+    }));
+
+    assert!(line.error("other", "a thing").column == 1);
+    assert!(line.line_error("an instruction").to_string()
+        == "line 3, column 1: expected an instruction, found \"toggle 1,x through 5,5\"");
+}
+
+#[test]
+fn test_validation() {
+    let is_bracket = |b| b == b'(' || b == b')';
+
+    assert!(Validation::Strict.check("(())\n", is_bracket, "( or )").is_ok());
+    assert!(Validation::Lenient.check(" ( )\n)\t\n", is_bracket, "( or )").is_ok());
+
+    let err = Validation::Strict.check("( )\n\n", is_bracket, "( or )").unwrap_err();
+    assert!(err.invalid == vec![InvalidByte { pos: 2, byte: b' ' }, InvalidByte { pos: 4, byte: b'\n' }]);
+    assert!(err.to_string() == "2 invalid bytes, expected ( or ): ' ' at 2 '\\n' at 4");
+
+    let err = Validation::Lenient.check("(x) \0", is_bracket, "( or )").unwrap_err();
+    assert!(err.invalid == vec![InvalidByte { pos: 2, byte: b'x' }, InvalidByte { pos: 5, byte: 0 }]);
+
+    let err = Validation::Lenient.check(&"x".repeat(12), is_bracket, "( or )").unwrap_err();
+    assert!(err.to_string().ends_with("'x' at 10 and 2 more"));
+
+    assert!("strict".parse::<Validation>() == Ok(Validation::Strict));
+    assert!("other".parse::<Validation>().is_err());
+}
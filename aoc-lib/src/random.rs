@@ -0,0 +1,106 @@
+//! Seeded random numbers for generating puzzle inputs
+
+use std::ops::RangeInclusive;
+
+/// A small, fast pseudo random number generator (SplitMix64). The same seed
+/// always produces the same sequence on every platform
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number from 0 to n - 1. n must not be zero
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Empty range");
+
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Returns a number in an inclusive range
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "Empty range");
+
+        let span = (hi as i128 - lo as i128 + 1) as u128;
+
+        if span > u64::MAX as u128 {
+            self.next_u64() as i64
+        } else {
+            (lo as i128 + self.below(span as u64) as i128) as i64
+        }
+    }
+
+    /// Returns a random index into a collection of a length
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// Returns true with a probability from 0 to 1
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Returns a random item from a slice, which must not be empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles a slice in place
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// Returns a string of characters chosen from an alphabet
+    pub fn string(&mut self, len: usize, alphabet: &str) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+
+        (0..len).map(|_| *self.choose(&chars)).collect()
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut rng1 = Rng::new(42);
+    let mut rng2 = Rng::new(42);
+
+    let seq1: Vec<u64> = (0..10).map(|_| rng1.next_u64()).collect();
+    let seq2: Vec<u64> = (0..10).map(|_| rng2.next_u64()).collect();
+    assert!(seq1 == seq2);
+    assert!(Rng::new(43).next_u64() != seq1[0]);
+
+    // First output of SplitMix64 seeded with 0
+    assert!(Rng::new(0).next_u64() == 0xe220_a839_7b1d_cdaf);
+
+    let mut rng = Rng::new(1);
+
+    for _ in 0..1000 {
+        assert!((-3..=3).contains(&rng.range(-3..=3)));
+        assert!(rng.below(5) < 5);
+    }
+
+    assert!(rng.range(7..=7) == 7);
+    assert!(rng.string(8, "ab").chars().all(|c| c == 'a' || c == 'b'));
+
+    let mut items: Vec<u32> = (0..20).collect();
+    rng.shuffle(&mut items);
+    items.sort();
+    assert!(items == (0..20).collect::<Vec<u32>>());
+}
@@ -0,0 +1,189 @@
+//! Running days and printing answers
+
+use crate::{input::INPUT_DIR_VAR, log::{self, Verbosity}, Day, Part, Solution};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, ValueEnum};
+use json::{object, JsonValue};
+use std::{error::Error, fs, io, path::PathBuf, process, time::{Duration, Instant}};
+
+/// Answer output format
+#[derive(ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable text
+    #[default]
+    Text,
+    /// One JSON object per line for each part
+    Json
+}
+
+/// Options controlling how days are run
+#[derive(Args, Debug, Default, Clone)]
+pub struct RunOptions {
+    /// Input file, or "-" to read standard input
+    #[arg(short, long)]
+    pub input: Option<String>,
+    /// Directory containing the default input files
+    #[arg(long, env = INPUT_DIR_VAR)]
+    pub input_dir: Option<PathBuf>,
+    /// Puzzle parameter override
+    #[arg(short, long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    pub params: Vec<(String, String)>,
+    /// Answer output format
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Diagnostic output verbosity
+    #[arg(long, value_enum, default_value_t)]
+    pub verbosity: Verbosity,
+    /// Write diagnostic output to a file instead of standard error
+    #[arg(long, value_name = "FILE")]
+    pub log_file: Option<PathBuf>
+}
+
+impl RunOptions {
+    /// Sets up diagnostic output from the verbosity and log file options
+    pub fn init_log(&self) -> io::Result<()> {
+        log::set_verbosity(self.verbosity);
+
+        if let Some(file) = &self.log_file {
+            log::set_output_file(file)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a NAME=VALUE parameter override
+fn parse_param(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err("expected NAME=VALUE".to_string())
+    }
+}
+
+/// Command line arguments for a single day binary
+#[derive(Parser)]
+struct DayArgs {
+    /// Part number (default all parts)
+    part: Option<u8>,
+    #[command(flatten)]
+    options: RunOptions
+}
+
+/// Entry point for a single day binary
+pub fn main(day: &Day) {
+    let command = DayArgs::command()
+        .name(format!("day{:02}", day.day))
+        .about(format!("Advent of Code 2015 day {}", day.day));
+
+    let args = DayArgs::from_arg_matches(&command.get_matches()).unwrap_or_else(|e| e.exit());
+
+    exit_on_error(args.options.init_log().map_err(|e| e.into()).and_then(|_| {
+        run_day(day, args.part, &args.options)
+    }))
+}
+
+/// Prints an error and exits with a non-zero status
+pub fn exit_on_error(result: Result<(), Box<dyn Error>>) {
+    log::flush();
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        process::exit(1)
+    }
+}
+
+/// Runs all parts of a day, or a single part, printing the answers
+pub fn run_day(day: &Day, part: Option<u8>, options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    let parts: Vec<_> = match part {
+        Some(part) => vec![day.part(part).ok_or_else(|| format!("Day {} has no part {}", day.day, part))?],
+        None => day.parts.iter().collect()
+    };
+
+    let mut input = day.load_input(options.input.as_deref(), options.input_dir.as_deref())?;
+
+    for (name, value) in &options.params {
+        if day.param(name).is_none() {
+            return Err(format!("Day {} has no parameter {}", day.day, name).into())
+        }
+
+        input.set_param(name, value);
+    }
+
+    for p in parts {
+        let start = Instant::now();
+        let solution = (p.solve)(&input)?;
+        let elapsed = start.elapsed();
+
+        match options.format {
+            Format::Text => print_text(p, &solution),
+            Format::Json => {
+                let mut object = solution_json(day, p, &solution, elapsed);
+
+                if let Some(bytes) = peak_memory() {
+                    object["peak_memory"] = bytes.into();
+                }
+
+                println!("{}", object.dump())
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints a solution as text
+fn print_text(part: &Part, solution: &Solution) {
+    println!("{} (part {}): {}", part.desc, part.part, solution.answer);
+
+    if log::enabled(Verbosity::Normal) {
+        for (name, value) in &solution.detail {
+            println!("    {}: {}", name, detail_text(value));
+        }
+    }
+}
+
+/// Formats an item of solution detail as text. Strings and lists of strings
+/// are printed as they are, anything else as JSON
+fn detail_text(value: &JsonValue) -> String {
+    if let Some(s) = value.as_str() {
+        return s.to_string()
+    }
+
+    if value.is_array() && value.members().all(|m| m.is_string()) {
+        return value.members().map(|m| m.as_str().unwrap()).collect::<Vec<_>>().join(", ")
+    }
+
+    value.dump()
+}
+
+/// Builds the JSON object for a solution
+pub fn solution_json(day: &Day, part: &Part, solution: &Solution, elapsed: Duration) -> JsonValue {
+    let mut object = object! {
+        day: day.day,
+        part: part.part,
+        answer: solution.answer.to_json(),
+        elapsed: elapsed.as_secs_f64()
+    };
+
+    if !solution.detail.is_empty() {
+        let mut detail = JsonValue::new_object();
+
+        for (name, value) in &solution.detail {
+            detail[*name] = value.clone();
+        }
+
+        object["detail"] = detail;
+    }
+
+    object
+}
+
+/// Returns the peak resident memory of this process so far in bytes, where the
+/// platform reports it
+pub fn peak_memory() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+
+    let kb = status.lines().find_map(|l| l.strip_prefix("VmHWM:"))?
+        .trim().strip_suffix("kB")?.trim().parse::<u64>().ok()?;
+
+    Some(kb * 1024)
+}
@@ -0,0 +1,42 @@
+//! Benchmarks for every solver on the committed puzzle inputs. Parts marked
+//! as slow in the answers manifest are only included if AOC_BENCH_SLOW is set
+
+use aoc2015::{check::{load_manifest, MANIFEST_FILE}, DAYS};
+use aoc_lib::Input;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::{env, path::Path, time::Duration};
+
+fn solvers(c: &mut Criterion) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let manifest = load_manifest(root.join(MANIFEST_FILE)).unwrap();
+    let slow = env::var_os("AOC_BENCH_SLOW").is_some();
+
+    for day in DAYS.iter() {
+        let entries: Vec<_> = manifest.iter()
+            .filter(|e| e.day == day.day && (slow || !e.slow))
+            .collect();
+
+        if entries.is_empty() {
+            continue
+        }
+
+        // One group per day with a benchmark for each part
+        let mut group = c.benchmark_group(format!("day{:02}", day.day));
+        group.sample_size(10).warm_up_time(Duration::from_millis(500));
+
+        for e in entries {
+            let part = day.part(e.part).unwrap();
+            let input = Input::from_file(root.join(&e.input)).unwrap();
+
+            group.bench_function(format!("part{}", e.part), |b| {
+                b.iter(|| (part.solve)(&input).unwrap())
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solvers);
+criterion_main!(benches);
@@ -0,0 +1,123 @@
+//! Checking solver answers against the expected answers manifest
+
+use crate::find_day;
+use aoc_lib::Input;
+use std::{error::Error, fs, path::Path, time::{Duration, Instant}};
+
+/// Default expected answers manifest file name
+pub const MANIFEST_FILE: &str = "answers.txt";
+
+/// An expected answer from the manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// Day number
+    pub day: u8,
+    /// Part number
+    pub part: u8,
+    /// Input file name, relative to the input directory
+    pub input: String,
+    /// Expected answer
+    pub answer: String,
+    /// Whether the part takes too long to run by default
+    pub slow: bool
+}
+
+/// Loads an expected answers manifest. Each non-blank line which is not a
+/// comment holds "day part input answer", optionally followed by "slow"
+pub fn load_manifest<P: AsRef<Path>>(file: P) -> Result<Vec<Expected>, Box<dyn Error>> {
+    let file = file.as_ref();
+
+    let text = fs::read_to_string(file)
+        .map_err(|e| format!("{}: {}", file.display(), e))?;
+
+    parse_manifest(&text).map_err(|e| format!("{}: {}", file.display(), e).into())
+}
+
+/// Parses the text of an expected answers manifest
+pub fn parse_manifest(text: &str) -> Result<Vec<Expected>, String> {
+    let mut manifest = Vec::new();
+
+    for (idx, l) in text.lines().enumerate() {
+        let l = l.trim();
+
+        if l.is_empty() || l.starts_with('#') {
+            continue
+        }
+
+        let terms: Vec<_> = l.split_whitespace().collect();
+
+        let slow = match terms.get(4) {
+            None => false,
+            Some(&"slow") if terms.len() == 5 => true,
+            _ => return Err(format!("line {}: expected \"day part input answer [slow]\" ({})", idx + 1, l))
+        };
+
+        if terms.len() < 4 {
+            return Err(format!("line {}: expected \"day part input answer [slow]\" ({})", idx + 1, l))
+        }
+
+        let day = terms[0].parse::<u8>().ok().filter(|d| find_day(*d).is_some())
+            .ok_or_else(|| format!("line {}: invalid day {}", idx + 1, terms[0]))?;
+
+        let part = terms[1].parse::<u8>().ok().filter(|p| find_day(day).unwrap().part(*p).is_some())
+            .ok_or_else(|| format!("line {}: invalid part {} for day {}", idx + 1, terms[1], day))?;
+
+        manifest.push(Expected {
+            day,
+            part,
+            input: terms[2].to_string(),
+            answer: terms[3].to_string(),
+            slow
+        });
+    }
+
+    Ok(manifest)
+}
+
+/// Result of checking one expected answer
+pub struct Outcome {
+    /// Answer returned by the solver, or the error it failed with
+    pub answer: Result<String, String>,
+    /// Time taken to load the input and solve
+    pub elapsed: Duration
+}
+
+impl Outcome {
+    /// Returns true if the solver returned the expected answer
+    pub fn passed(&self, expected: &Expected) -> bool {
+        matches!(&self.answer, Ok(answer) if *answer == expected.answer)
+    }
+}
+
+/// Runs the solver for an expected answer
+pub fn check(expected: &Expected, input_dir: &Path) -> Outcome {
+    let start = Instant::now();
+
+    let answer = find_day(expected.day)
+        .and_then(|day| day.part(expected.part))
+        .ok_or_else(|| "No such day or part".to_string())
+        .and_then(|part| {
+            let input = Input::from_file(input_dir.join(&expected.input)).map_err(|e| e.to_string())?;
+
+            (part.solve)(&input).map(|s| s.answer.to_string()).map_err(|e| e.to_string())
+        });
+
+    Outcome {
+        answer,
+        elapsed: start.elapsed()
+    }
+}
+
+#[test]
+fn test_parse_manifest() {
+    let manifest = parse_manifest("# Comment\n\n1 1 input01.txt 74\n19 2 input19.txt 195 slow\n").unwrap();
+
+    assert!(manifest.len() == 2);
+    assert!(manifest[0] == Expected { day: 1, part: 1, input: "input01.txt".to_string(), answer: "74".to_string(), slow: false });
+    assert!(manifest[1].slow);
+
+    assert!(parse_manifest("1 1 input01.txt").is_err());
+    assert!(parse_manifest("1 1 input01.txt 74 fast").is_err());
+    assert!(parse_manifest("26 1 input26.txt 1").is_err());
+    assert!(parse_manifest("25 2 input25.txt 1").is_err());
+}
@@ -0,0 +1,88 @@
+//! Downloading puzzle inputs into the input directory
+
+use aoc_lib::Day;
+use std::{error::Error, fs, path::{Path, PathBuf}};
+
+/// Default server to download puzzle inputs from
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the puzzle server base URL
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the session token
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Puzzle year the inputs are fetched for
+pub const YEAR: u16 = 2015;
+
+/// What fetching a day's input did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input file was already present
+    Cached(PathBuf),
+    /// The input was downloaded and saved, with its size in bytes
+    Downloaded(PathBuf, usize)
+}
+
+/// Downloads puzzle inputs from a server using a session token
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent
+}
+
+impl Fetcher {
+    /// Builds a fetcher for a server. The session token is only needed if an
+    /// input has to be downloaded
+    pub fn new(base_url: &str, session: Option<&str>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(|s| s.trim().to_string()),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc2015/", env!("CARGO_PKG_VERSION")))
+                .build()
+        }
+    }
+
+    /// Returns the URL of a day's input
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, YEAR, day)
+    }
+
+    /// Fetches a day's input into the input directory, unless the input file
+    /// is already there
+    pub fn fetch(&self, day: &Day, input_dir: &Path) -> Result<Fetched, Box<dyn Error>> {
+        let file = match day.input {
+            Some(file) => input_dir.join(file),
+            None => return Err(format!("Day {} has no puzzle input", day.day).into())
+        };
+
+        if file.exists() {
+            return Ok(Fetched::Cached(file))
+        }
+
+        let session = self.session.as_deref().filter(|s| !s.is_empty())
+            .ok_or_else(|| format!("A session token is needed to download day {} (set {} or use --session)", day.day, SESSION_VAR))?;
+
+        let url = self.url(day.day);
+
+        // Errors from the request include the URL
+        let response = self.agent.get(&url).set("Cookie", &format!("session={}", session)).call()?;
+        let text = response.into_string().map_err(|e| format!("{}: {}", url, e))?;
+
+        if text.is_empty() {
+            return Err(format!("{}: server returned an empty input", url).into())
+        }
+
+        // Write to a temporary file first so an interrupted download is never
+        // mistaken for a cached input
+        fs::create_dir_all(input_dir).map_err(|e| format!("{}: {}", input_dir.display(), e))?;
+
+        let partial = file.with_extension("part");
+
+        fs::write(&partial, &text).map_err(|e| format!("{}: {}", partial.display(), e))?;
+        fs::rename(&partial, &file).map_err(|e| format!("{}: {}", file.display(), e))?;
+
+        Ok(Fetched::Downloaded(file, text.len()))
+    }
+}
@@ -0,0 +1,49 @@
+//! Registry of the Advent of Code 2015 days
+
+use aoc_lib::Day;
+
+pub mod check;
+pub mod fetch;
+pub mod parallel;
+
+/// All registered days in day order
+pub static DAYS: [&Day; 25] = [
+    &day01::DAY,
+    &day02::DAY,
+    &day03::DAY,
+    &day04::DAY,
+    &day05::DAY,
+    &day06::DAY,
+    &day07::DAY,
+    &day08::DAY,
+    &day09::DAY,
+    &day10::DAY,
+    &day11::DAY,
+    &day12::DAY,
+    &day13::DAY,
+    &day14::DAY,
+    &day15::DAY,
+    &day16::DAY,
+    &day17::DAY,
+    &day18::DAY,
+    &day19::DAY,
+    &day20::DAY,
+    &day21::DAY,
+    &day22::DAY,
+    &day23::DAY,
+    &day24::DAY,
+    &day25::DAY,
+];
+
+/// Looks up a registered day by number
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day).copied()
+}
+
+#[test]
+fn test_registry() {
+    for (i, day) in DAYS.iter().enumerate() {
+        assert!(day.day as usize == i + 1, "Day {} registered in position {}", day.day, i + 1);
+        assert!(!day.parts.is_empty());
+    }
+}
@@ -0,0 +1,311 @@
+use aoc2015::{
+    check::{self, MANIFEST_FILE}, fetch::{Fetched, Fetcher, BASE_URL, BASE_URL_VAR, SESSION_VAR}, find_day,
+    parallel::{Job, ParallelRunner, Report, Status}, DAYS
+};
+use aoc_lib::{input::INPUT_DIR_VAR, Rng, runner::{exit_on_error, run_day, Format, RunOptions}};
+use clap::{Parser, Subcommand};
+use std::{cmp::Reverse, env, error::Error, ffi::OsString, path::PathBuf, str::FromStr, time::{Duration, Instant}};
+
+/// Advent of Code 2015 solutions
+#[derive(Parser)]
+#[command(name = "aoc2015")]
+struct Args {
+    #[command(subcommand)]
+    command: Command
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs a day's solutions, or all of them
+    Run {
+        /// Day number or "all"
+        day: DaySel,
+        /// Part number (default all parts)
+        part: Option<u8>,
+        /// Number of parts to run at once when running all days (default the number of CPUs)
+        #[arg(short, long)]
+        jobs: Option<usize>,
+        /// Time limit in seconds for each part when running all days, or 0 for none
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+        #[command(flatten)]
+        options: RunOptions
+    },
+    /// Checks answers against the expected answers manifest
+    Check {
+        /// Day number or "all"
+        #[arg(default_value = "all")]
+        day: DaySel,
+        /// Expected answers manifest
+        #[arg(short, long, default_value = MANIFEST_FILE)]
+        manifest: PathBuf,
+        /// Directory containing the input files (default the manifest's directory)
+        #[arg(long, env = INPUT_DIR_VAR)]
+        input_dir: Option<PathBuf>,
+        /// Also check parts marked as slow
+        #[arg(long)]
+        slow: bool
+    },
+    /// Downloads puzzle inputs which are not already in the input directory
+    Fetch {
+        /// Day number or "all"
+        #[arg(default_value = "all")]
+        day: DaySel,
+        /// Directory to save the input files in (default the current directory)
+        #[arg(long, env = INPUT_DIR_VAR)]
+        input_dir: Option<PathBuf>,
+        /// Session token cookie from a logged in browser
+        #[arg(long, env = SESSION_VAR, hide_env_values = true)]
+        session: Option<String>,
+        /// Base URL of the puzzle server
+        #[arg(long, env = BASE_URL_VAR, default_value = BASE_URL)]
+        base_url: String
+    },
+    /// Prints a random puzzle input for a day
+    Generate {
+        /// Day number
+        day: u8,
+        /// Input size, for example the number of lines
+        size: usize,
+        /// Random number seed
+        #[arg(short, long, default_value_t = 1)]
+        seed: u64
+    },
+    /// Lists the registered days and parts
+    List
+}
+
+/// Day selection on the command line
+#[derive(Clone)]
+enum DaySel {
+    All,
+    Day(u8)
+}
+
+impl FromStr for DaySel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySel::All)
+        }
+
+        match s.parse::<u8>() {
+            Ok(day) if find_day(day).is_some() => Ok(DaySel::Day(day)),
+            _ => Err(format!("expected a day number from 1 to {} or \"all\"", DAYS.len()))
+        }
+    }
+}
+
+fn main() {
+    exit_on_error(run(Args::parse()))
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    match args.command {
+        Command::Run { day: DaySel::All, part, jobs, timeout, options } => {
+            options.init_log()?;
+
+            if options.input.is_some() || !options.params.is_empty() {
+                return Err("An input file or parameters can only be given when running a single day".into())
+            }
+
+            run_all(part, jobs, timeout, &options)?;
+        }
+        Command::Run { day: DaySel::Day(day), part, options, .. } => {
+            options.init_log()?;
+            run_day(find_day(day).unwrap(), part, &options)?;
+        }
+        Command::Check { day, manifest, input_dir, slow } => {
+            run_check(day, manifest, input_dir, slow)?;
+        }
+        Command::Fetch { day, input_dir, session, base_url } => {
+            run_fetch(day, input_dir, session, base_url)?;
+        }
+        Command::Generate { day, size, seed } => {
+            let day = find_day(day).ok_or_else(|| format!("Day {} not found", day))?;
+
+            print!("{}", (day.generate)(&mut Rng::new(seed), size));
+        }
+        Command::List => {
+            for day in DAYS.iter() {
+                for part in day.parts {
+                    println!("Day {:2} part {}: {}", day.day, part.part, part.desc);
+                }
+
+                for param in day.params {
+                    println!("Day {:2} param {}: {}", day.day, param.name, param.desc);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run_all(part: Option<u8>, threads: Option<usize>, timeout: u64, options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    let jobs: Vec<Job> = DAYS.iter().flat_map(|day| {
+        day.parts.iter().filter(|p| part.is_none_or(|part| p.part == part)).map(move |p| Job { day: day.day, part: p.part })
+    }).collect();
+
+    let mut runner = ParallelRunner::new(env::current_exe()?)
+        .timeout(if timeout == 0 { None } else { Some(Duration::from_secs(timeout)) });
+
+    if let Some(threads) = threads {
+        runner = runner.threads(threads);
+    }
+
+    if let Some(dir) = &options.input_dir {
+        runner = runner.args([OsString::from("--input-dir"), dir.clone().into_os_string()]);
+    }
+
+    let start = Instant::now();
+    let mut reports = runner.run(&jobs);
+    let wall = start.elapsed();
+
+    // Most expensive first
+    reports.sort_by_key(|r| Reverse(r.elapsed));
+
+    match options.format {
+        Format::Text => print_report_table(&reports, wall),
+        Format::Json => {
+            for r in &reports {
+                println!("{}", report_json(r).dump());
+            }
+        }
+    }
+
+    // Parts which time out count as failures
+    let failed = reports.iter().filter(|r| !matches!(r.status, Status::Solved(_))).count();
+
+    if failed > 0 {
+        return Err(format!("{} parts failed or timed out", failed).into())
+    }
+
+    Ok(())
+}
+
+fn print_report_table(reports: &[Report], wall: Duration) {
+    let answers: Vec<String> = reports.iter().map(|r| match &r.status {
+        Status::Solved(answer) => answer.clone(),
+        Status::Failed(error) => format!("FAILED: {}", error),
+        Status::TimedOut => "TIMED OUT".to_string()
+    }).collect();
+
+    let width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max("Answer".len());
+
+    println!("Day Part  {:width$}  {:>10}  {:>10}", "Answer", "Time", "Memory", width = width);
+
+    for (r, answer) in reports.iter().zip(&answers) {
+        let memory = match r.peak_memory {
+            Some(bytes) => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+            None => "-".to_string()
+        };
+
+        println!("{:3} {:4}  {:width$}  {:9.3}s  {:>10}", r.job.day, r.job.part, answer, r.elapsed.as_secs_f64(), memory,
+            width = width);
+    }
+
+    let count = |f: fn(&Status) -> bool| reports.iter().filter(|r| f(&r.status)).count();
+    let total: Duration = reports.iter().map(|r| r.elapsed).sum();
+
+    println!("{} solved, {} failed, {} timed out in {:.3}s ({:.3}s total part time)",
+        count(|s| matches!(s, Status::Solved(_))), count(|s| matches!(s, Status::Failed(_))),
+        count(|s| *s == Status::TimedOut), wall.as_secs_f64(), total.as_secs_f64());
+}
+
+fn report_json(r: &Report) -> json::JsonValue {
+    let mut object = match (&r.status, &r.json) {
+        (Status::Solved(_), Some(object)) => object.clone(),
+        (Status::Failed(error), _) => json::object! { day: r.job.day, part: r.job.part, error: error.as_str() },
+        _ => json::object! { day: r.job.day, part: r.job.part, error: "timed out" }
+    };
+
+    object["elapsed"] = r.elapsed.as_secs_f64().into();
+
+    object
+}
+
+fn run_check(day: DaySel, manifest: PathBuf, input_dir: Option<PathBuf>, slow: bool) -> Result<(), Box<dyn Error>> {
+    let expected = check::load_manifest(&manifest)?;
+
+    let input_dir = input_dir.unwrap_or_else(|| {
+        manifest.parent().map(|p| p.to_path_buf()).unwrap_or_default()
+    });
+
+    let start = Instant::now();
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut skipped = 0;
+
+    for e in &expected {
+        if let DaySel::Day(day) = day {
+            if e.day != day {
+                continue
+            }
+        }
+
+        if e.slow && !slow {
+            println!("Day {:2} part {}: skipped (slow)", e.day, e.part);
+            skipped += 1;
+            continue
+        }
+
+        let outcome = check::check(e, &input_dir);
+
+        match &outcome.answer {
+            _ if outcome.passed(e) => {
+                println!("Day {:2} part {}: pass {:10.3}s", e.day, e.part, outcome.elapsed.as_secs_f64());
+                passed += 1;
+            }
+            Ok(answer) => {
+                println!("Day {:2} part {}: FAIL {:10.3}s (expected {}, got {})", e.day, e.part,
+                    outcome.elapsed.as_secs_f64(), e.answer, answer);
+                failed += 1;
+            }
+            Err(error) => {
+                println!("Day {:2} part {}: FAIL {:10.3}s ({})", e.day, e.part, outcome.elapsed.as_secs_f64(), error);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} skipped in {:.3}s", passed, failed, skipped, start.elapsed().as_secs_f64());
+
+    if failed > 0 {
+        return Err(format!("{} answers did not match", failed).into())
+    }
+
+    Ok(())
+}
+
+fn run_fetch(day: DaySel, input_dir: Option<PathBuf>, session: Option<String>, base_url: String) -> Result<(), Box<dyn Error>> {
+    let fetcher = Fetcher::new(&base_url, session.as_deref());
+    let input_dir = input_dir.unwrap_or_default();
+
+    let mut failed = 0;
+
+    for d in DAYS.iter() {
+        if let DaySel::Day(day) = day {
+            if d.day != day {
+                continue
+            }
+        }
+
+        // Carry on with the other days after a failure
+        match fetcher.fetch(d, &input_dir) {
+            Ok(Fetched::Cached(file)) => println!("Day {:2}: {} already present", d.day, file.display()),
+            Ok(Fetched::Downloaded(file, bytes)) => println!("Day {:2}: saved {} ({} bytes)", d.day, file.display(), bytes),
+            Err(e) => {
+                eprintln!("Day {:2}: {}", d.day, e);
+                failed += 1;
+            }
+        }
+    }
+
+    match (day, failed) {
+        (_, 0) => Ok(()),
+        (DaySel::Day(_), _) => Err("fetch failed".into()),
+        (DaySel::All, _) => Err(format!("{} days failed to fetch", failed).into())
+    }
+}
@@ -0,0 +1,201 @@
+//! Running days in parallel, each part in its own child process so that it
+//! can be stopped when it runs for too long and its memory use measured
+
+use std::{
+    ffi::OsString, io::Read, path::PathBuf, process::{Child, Command, Stdio},
+    sync::{atomic::{AtomicUsize, Ordering}, mpsc}, thread, time::{Duration, Instant}
+};
+
+/// How often running child processes are checked for completion
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// One part of a day to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Job {
+    pub day: u8,
+    pub part: u8
+}
+
+/// How a part finished
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Solved with an answer
+    Solved(String),
+    /// Failed with an error message
+    Failed(String),
+    /// Stopped after running for longer than the time limit
+    TimedOut
+}
+
+/// The outcome of running one part
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub job: Job,
+    pub status: Status,
+    /// Time taken to solve the part, or the wall time of the child process if
+    /// the part did not report one
+    pub elapsed: Duration,
+    /// Peak resident memory of the child process in bytes, if known
+    pub peak_memory: Option<u64>,
+    /// JSON object reported by the child process for a solved part
+    pub json: Option<json::JsonValue>
+}
+
+/// Runs parts of days in child processes on a pool of worker threads
+pub struct ParallelRunner {
+    exe: PathBuf,
+    args: Vec<OsString>,
+    threads: usize,
+    timeout: Option<Duration>
+}
+
+impl ParallelRunner {
+    /// Builds a runner which starts the aoc2015 executable at a path
+    pub fn new(exe: PathBuf) -> Self {
+        Self {
+            exe,
+            args: Vec::new(),
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: None
+        }
+    }
+
+    /// Adds arguments passed to every "run" command
+    pub fn args<I: IntoIterator<Item = S>, S: Into<OsString>>(mut self, args: I) -> Self {
+        self.args.extend(args.into_iter().map(|a| a.into()));
+        self
+    }
+
+    /// Sets the number of parts run at once (default the number of CPUs)
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Sets the time limit for each part (default none)
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Runs the jobs, returning a report for each in the same order
+    pub fn run(&self, jobs: &[Job]) -> Vec<Report> {
+        let next = AtomicUsize::new(0);
+        let (tx, rx) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads.min(jobs.len()) {
+                let tx = tx.clone();
+                let next = &next;
+
+                scope.spawn(move || {
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+
+                        if idx >= jobs.len() {
+                            break
+                        }
+
+                        tx.send((idx, self.run_job(jobs[idx]))).unwrap();
+                    }
+                });
+            }
+        });
+
+        drop(tx);
+
+        let mut reports: Vec<(usize, Report)> = rx.iter().collect();
+        reports.sort_by_key(|(idx, _)| *idx);
+
+        reports.into_iter().map(|(_, report)| report).collect()
+    }
+
+    fn run_job(&self, job: Job) -> Report {
+        let start = Instant::now();
+
+        let child = Command::new(&self.exe)
+            .args(["run", &job.day.to_string(), &job.part.to_string(), "--format", "json", "--verbosity", "quiet"])
+            .args(&self.args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+
+        let report = |status, elapsed| Report { job, status, elapsed, peak_memory: None, json: None };
+
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => return report(Status::Failed(format!("{}: {}", self.exe.display(), e)), start.elapsed())
+        };
+
+        // Read the output as it is written so the child never blocks on a full pipe
+        let stdout = read_in_background(child.stdout.take());
+        let stderr = read_in_background(child.stderr.take());
+
+        let exit = match wait_with_timeout(&mut child, self.timeout) {
+            Some(exit) => exit,
+            None => return report(Status::TimedOut, start.elapsed())
+        };
+
+        let wall = start.elapsed();
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+
+        if !exit.success() {
+            let message = stderr.trim().trim_start_matches("Error: ");
+            let message = if message.is_empty() { exit.to_string() } else { message.to_string() };
+
+            return report(Status::Failed(message), wall)
+        }
+
+        match stdout.lines().next().map(json::parse) {
+            Some(Ok(object)) => {
+                let answer = &object["answer"];
+                let answer = answer.as_str().map(String::from).unwrap_or_else(|| answer.dump());
+
+                Report {
+                    job,
+                    status: Status::Solved(answer),
+                    elapsed: object["elapsed"].as_f64().map_or(wall, Duration::from_secs_f64),
+                    peak_memory: object["peak_memory"].as_u64(),
+                    json: Some(object)
+                }
+            }
+            _ => report(Status::Failed(format!("Unexpected output: {}", stdout.trim())), wall)
+        }
+    }
+}
+
+fn read_in_background<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut text);
+        }
+
+        text
+    })
+}
+
+/// Waits for a child process to exit, killing it if it runs past a timeout
+fn wait_with_timeout(child: &mut Child, timeout: Option<Duration>) -> Option<std::process::ExitStatus> {
+    let start = Instant::now();
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(exit)) => return Some(exit),
+            Ok(None) => (),
+            Err(_) => return child.wait().ok()
+        }
+
+        if timeout.is_some_and(|t| start.elapsed() >= t) {
+            let _ = child.kill();
+            let _ = child.wait();
+
+            return None
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
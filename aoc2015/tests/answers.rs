@@ -0,0 +1,31 @@
+use aoc2015::check::{check, load_manifest, MANIFEST_FILE};
+use std::path::Path;
+
+fn check_answers(slow: bool) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let manifest = load_manifest(root.join(MANIFEST_FILE)).unwrap();
+
+    let failures: Vec<String> = manifest.iter().filter(|e| e.slow == slow).filter_map(|e| {
+        let outcome = check(e, &root);
+
+        if outcome.passed(e) {
+            None
+        } else {
+            Some(format!("Day {} part {}: expected {}, got {:?}", e.day, e.part, e.answer, outcome.answer))
+        }
+    }).collect();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn answers() {
+    check_answers(false);
+}
+
+#[test]
+#[ignore]
+fn slow_answers() {
+    check_answers(true);
+}
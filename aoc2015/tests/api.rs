@@ -0,0 +1,46 @@
+use aoc_lib::Input;
+
+#[test]
+fn day07_circuit() {
+    let input = Input::from("123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nx LSHIFT 2 -> f\n\
+        y RSHIFT 2 -> g\nNOT x -> h\nNOT y -> i\n");
+
+    let gates = day07::parse_gates(&input.numbered_lines()).unwrap();
+
+    let mut signals = day07::get_signals(&gates);
+    day07::resolve_gates(gates, &mut signals).unwrap();
+
+    for (name, value) in [("d", 72), ("e", 507), ("f", 492), ("g", 114), ("h", 65412), ("i", 65079), ("x", 123), ("y", 456)] {
+        assert!(day07::get_signal_value(&signals, name) == Some(value), "Signal {}", name);
+    }
+}
+
+#[test]
+fn day23_program() {
+    let input = Input::from("inc a\njio a, +2\ntpl a\ninc a\n");
+
+    let instructions = day23::parse_instructions(&input.numbered_lines()).unwrap();
+    let state = day23::run_program(&instructions, 0);
+
+    assert!(state.a == 2);
+    assert!(state.b == 0);
+}
+
+#[test]
+fn day21_play() {
+    let mut player = day21::PersonProfile { hit_points: 8, damage: 5, armor: 5 };
+    let boss = day21::PersonProfile { hit_points: 12, damage: 7, armor: 2 };
+
+    assert!(day21::play(&mut player, &boss));
+    assert!(player.hit_points == 2);
+}
+
+#[test]
+fn day22_play() {
+    let boss = day22::BossState { hit_points: 13, damage: 8 };
+
+    let result = day22::play(day22::Difficulty::Normal, &boss);
+
+    // Four magic missiles
+    assert!(result.min_spend == 4 * 53);
+}
@@ -0,0 +1,113 @@
+use aoc2015::{fetch::{Fetched, Fetcher}, find_day};
+use std::{fs, io::{BufRead, BufReader, Write}, net::TcpListener, path::PathBuf, process::Command, sync::{Arc, Mutex}, thread};
+
+/// Starts a stub puzzle server which serves day 1 to the "good" session and
+/// records each request's path and cookie. Returns its base URL
+fn stub_server(requests: Arc<Mutex<Vec<String>>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+            let path = request.split_whitespace().nth(1).unwrap_or("").to_string();
+
+            let mut cookie = String::new();
+
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+
+                if header.trim().is_empty() {
+                    break
+                }
+
+                if let Some(value) = header.strip_prefix("Cookie: ") {
+                    cookie = value.trim().to_string();
+                }
+            }
+
+            let response = match (path.as_str(), cookie.as_str()) {
+                ("/2015/day/1/input", "session=good") => "HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\n(()))\n",
+                ("/2015/day/1/input", _) => "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n",
+                _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n"
+            };
+
+            requests.lock().unwrap().push(format!("{} {}", path, cookie));
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    url
+}
+
+fn input_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+
+    dir
+}
+
+#[test]
+fn fetch_downloads_once() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let url = stub_server(requests.clone());
+    let dir = input_dir("fetch_downloads_once");
+    let file = dir.join("input01.txt");
+
+    let fetcher = Fetcher::new(&url, Some("good"));
+
+    assert!(fetcher.fetch(find_day(1).unwrap(), &dir).unwrap() == Fetched::Downloaded(file.clone(), 6));
+    assert!(fs::read_to_string(&file).unwrap() == "(()))\n");
+
+    // The cached file is used without a session or another request
+    let fetcher = Fetcher::new(&url, None);
+
+    assert!(fetcher.fetch(find_day(1).unwrap(), &dir).unwrap() == Fetched::Cached(file));
+    assert!(*requests.lock().unwrap() == vec!["/2015/day/1/input session=good"]);
+}
+
+#[test]
+fn fetch_errors() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let url = stub_server(requests.clone());
+    let dir = input_dir("fetch_errors");
+
+    // No session token
+    assert!(Fetcher::new(&url, None).fetch(find_day(1).unwrap(), &dir).is_err());
+    assert!(requests.lock().unwrap().is_empty());
+
+    // Rejected session token
+    assert!(Fetcher::new(&url, Some("bad")).fetch(find_day(1).unwrap(), &dir).is_err());
+
+    // Not found
+    assert!(Fetcher::new(&url, Some("good")).fetch(find_day(2).unwrap(), &dir).is_err());
+
+    assert!(requests.lock().unwrap().len() == 2);
+    assert!(!dir.join("input01.txt").exists() && !dir.join("input02.txt").exists());
+}
+
+#[test]
+fn fetch_all_continues_after_failures() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let url = stub_server(requests.clone());
+    let dir = input_dir("fetch_all_continues_after_failures");
+
+    // Only day 1 is served, so every other day fails
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc2015"))
+        .args(["fetch", "all", "--session", "good", "--base-url", &url, "--input-dir"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("Day 25:") && stderr.contains("24 days failed to fetch"));
+    assert!(requests.lock().unwrap().len() == 25);
+    assert!(fs::read_to_string(dir.join("input01.txt")).unwrap() == "(()))\n");
+}
@@ -0,0 +1,34 @@
+use aoc2015::DAYS;
+use aoc_lib::{Input, Rng};
+
+/// Small input sizes which every part can solve quickly
+const SIZES: [usize; 25] = [200, 50, 200, 4, 20, 10, 30, 20, 6, 8, 8, 30, 5, 5, 4, 20, 12, 10, 5, 50, 100, 10, 10, 30, 50];
+
+/// Parts which are too slow to solve in a debug build, whatever the input size
+const SKIP: [(u8, u8); 4] = [(4, 1), (4, 2), (10, 2), (19, 2)];
+
+#[test]
+fn generated_inputs_solve() {
+    for day in DAYS.iter() {
+        for seed in 0..3 {
+            let text = (day.generate)(&mut Rng::new(seed), SIZES[day.day as usize - 1]);
+            let input = Input::from(text.as_str());
+
+            for part in day.parts.iter().filter(|p| !SKIP.contains(&(day.day, p.part))) {
+                if let Err(e) = (part.solve)(&input) {
+                    panic!("Day {} part {} failed with seed {}: {}\n{}", day.day, part.part, seed, e, text);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generators_are_seeded() {
+    for day in DAYS.iter() {
+        let text1 = (day.generate)(&mut Rng::new(7), 10);
+        let text2 = (day.generate)(&mut Rng::new(7), 10);
+
+        assert!(text1 == text2, "Day {} generated different inputs from the same seed", day.day);
+    }
+}
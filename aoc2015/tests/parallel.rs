@@ -0,0 +1,29 @@
+use aoc2015::parallel::{Job, ParallelRunner, Status};
+use std::{path::{Path, PathBuf}, time::Duration};
+
+fn runner(input_dir: &Path) -> ParallelRunner {
+    ParallelRunner::new(PathBuf::from(env!("CARGO_BIN_EXE_aoc2015")))
+        .args([Path::new("--input-dir"), input_dir])
+        .threads(2)
+        .timeout(Some(Duration::from_secs(1)))
+}
+
+#[test]
+fn parallel_run() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let jobs = [Job { day: 1, part: 1 }, Job { day: 19, part: 2 }, Job { day: 1, part: 2 }];
+    let reports = runner(&root).run(&jobs);
+
+    assert!(reports.iter().map(|r| r.job).collect::<Vec<_>>() == jobs);
+    assert!(reports[0].status == Status::Solved("74".to_string()));
+    assert!(reports[1].status == Status::TimedOut && reports[1].elapsed >= Duration::from_secs(1));
+    assert!(reports[2].status == Status::Solved("1795".to_string()));
+}
+
+#[test]
+fn parallel_run_failure() {
+    let reports = runner(Path::new("no-such-directory")).run(&[Job { day: 2, part: 1 }]);
+
+    assert!(matches!(&reports[0].status, Status::Failed(e) if e.contains("input02.txt")));
+}
@@ -0,0 +1,79 @@
+//! Property tests checking fast implementations against simpler reference
+//! implementations on random inputs. Failures are shrunk to a minimal case
+
+use day04::{calc_hash_number, calc_hash_number_threaded, pow::{first_in_chunks, Search, Target}};
+use day14::{race, Reindeer};
+use day20::{first_house, first_house_lazy, first_house_sieve, prime_fact_sum};
+use md5::Digest;
+use quickcheck::{quickcheck, TestResult};
+
+/// A cheap check needing two leading zero hex digits, so searches are short
+fn check2(digest: Digest) -> bool {
+    digest[0] == 0
+}
+
+quickcheck! {
+    fn day04_threaded_matches_sequential(key: String) -> TestResult {
+        if key.len() > 20 {
+            return TestResult::discard()
+        }
+
+        TestResult::from_bool(calc_hash_number_threaded(&key, check2) == Some(calc_hash_number(&key, check2)))
+    }
+
+    fn day04_chunked_search_matches_sequential(key: String, threads: u8, chunk: u8) -> TestResult {
+        if key.len() > 20 {
+            return TestResult::discard()
+        }
+
+        let target = Target::zero_digits(2).unwrap();
+        let expected = Search::new(&key, target.clone()).find();
+
+        // Any number of threads and any chunk size, down to one nonce
+        let search = |nonces: std::ops::Range<u64>| Search::new(&key, target.clone()).range(nonces).find();
+        let found = first_in_chunks(0..u64::MAX, threads as usize % 16 + 1, chunk as u64 + 1, search);
+
+        TestResult::from_bool(found == expected)
+    }
+
+    fn day14_race_matches_closed_form(specs: Vec<(u8, u8, u8)>, duration: u16) -> TestResult {
+        if specs.is_empty() || specs.len() > 10 {
+            return TestResult::discard()
+        }
+
+        // Speeds and durations of at least 1
+        let reindeer: Vec<Reindeer> = specs.iter().map(|&(speed, travel, rest)| {
+            Reindeer::new(speed as u32 % 30 + 1, travel as u32 % 20 + 1, rest as u32 % 200 + 1)
+        }).collect();
+
+        let duration = duration as u32 % 3000;
+        let standings = race(&reindeer, duration);
+
+        let distances_match = reindeer.iter().zip(&standings).all(|(r, s)| s.dist == r.distance(duration));
+        let points: u32 = standings.iter().map(|s| s.points).sum();
+
+        // At least one point is awarded every second
+        TestResult::from_bool(distances_match && points >= duration)
+    }
+
+    fn day20_divisor_sums_match(n: u16) -> bool {
+        // The prime list is built up as the numbers are factorised in order
+        let mut primes = Vec::new();
+
+        (1..=n as u64 % 5000 + 1).all(|i| {
+            prime_fact_sum(i, &mut primes) == (1..=i).filter(|d| i % d == 0).sum::<u64>()
+        })
+    }
+
+    fn day20_first_house_matches_sieve(presents: u32) -> bool {
+        let presents = presents as u64 % 50_000 + 1;
+
+        first_house(presents) == first_house_sieve(presents, 10, None)
+    }
+
+    fn day20_lazy_first_house_matches_sieve(presents: u32) -> bool {
+        let presents = presents as u64 % 50_000 + 1;
+
+        first_house_lazy(presents) as u64 == first_house_sieve(presents, 11, Some(50))
+    }
+}
@@ -0,0 +1,120 @@
+//! Fixed size sets of item indices stored as bitmasks
+
+use std::fmt::{self, Display};
+
+const WORD_BITS: usize = 64;
+
+/// A set of item indices from 0 to the capacity of the set
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet {
+    words: Box<[u64]>
+}
+
+impl BitSet {
+    /// Builds an empty set able to hold indices below a capacity
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(WORD_BITS)].into_boxed_slice()
+        }
+    }
+
+    /// Builds a set containing every index below a count
+    pub fn full(count: usize) -> Self {
+        let mut set = Self::new(count);
+
+        for i in 0..count {
+            set.insert(i);
+        }
+
+        set
+    }
+
+    pub fn insert(&mut self, i: usize) {
+        self.words[i / WORD_BITS] |= 1 << (i % WORD_BITS);
+    }
+
+    pub fn remove(&mut self, i: usize) {
+        self.words[i / WORD_BITS] &= !(1 << (i % WORD_BITS));
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.words.get(i / WORD_BITS).is_some_and(|w| w & (1 << (i % WORD_BITS)) != 0)
+    }
+
+    /// Returns the number of indices in the set
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns true if the sets have no index in common
+    pub fn is_disjoint(&self, other: &BitSet) -> bool {
+        self.words.iter().zip(other.words.iter()).all(|(a, b)| a & b == 0)
+    }
+
+    /// Returns the indices in either set. Both sets must have the same capacity
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        BitSet {
+            words: self.words.iter().zip(other.words.iter()).map(|(a, b)| a | b).collect()
+        }
+    }
+
+    /// Iterates the indices in the set in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            (0..WORD_BITS).filter(move |b| word & (1 << b) != 0).map(move |b| w * WORD_BITS + b)
+        })
+    }
+
+    /// Returns the items selected by the set
+    pub fn select<'a, T>(&'a self, items: &'a [T]) -> impl Iterator<Item = &'a T> + 'a {
+        self.iter().map(move |i| &items[i])
+    }
+
+    /// Formats the items selected by the set separated by a string
+    pub fn join<T: Display>(&self, items: &[T], sep: &str) -> String {
+        self.select(items).map(|i| i.to_string()).collect::<Vec<_>>().join(sep)
+    }
+}
+
+impl fmt::Display for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indices: Vec<String> = self.iter().map(|i| i.to_string()).collect();
+
+        write!(f, "{{{}}}", indices.join(", "))
+    }
+}
+
+#[test]
+fn test_bitset() {
+    let mut set = BitSet::new(130);
+
+    set.insert(0);
+    set.insert(64);
+    set.insert(129);
+    assert!(set.len() == 3);
+    assert!(set.contains(64) && !set.contains(63) && !set.contains(500));
+    assert!(set.iter().collect::<Vec<_>>() == vec![0, 64, 129]);
+    assert!(set.to_string() == "{0, 64, 129}");
+
+    let mut other = BitSet::new(130);
+    other.insert(1);
+    assert!(set.is_disjoint(&other));
+
+    other.insert(129);
+    assert!(!set.is_disjoint(&other));
+    assert!(set.union(&other).len() == 4);
+
+    set.remove(129);
+    assert!(set.len() == 2 && !set.is_empty());
+    assert!(BitSet::full(70).len() == 70);
+
+    let items = ["a", "b", "c"];
+    let mut set = BitSet::new(3);
+    set.insert(0);
+    set.insert(2);
+    assert!(set.join(&items, "+") == "a+c");
+}
@@ -0,0 +1,7 @@
+//! Subset enumeration over any number of items
+
+pub mod bitset;
+pub mod subset;
+
+pub use bitset::BitSet;
+pub use subset::SubsetSum;
@@ -0,0 +1,169 @@
+//! Subsets of items whose values add up to a target
+
+use crate::BitSet;
+use std::ops::RangeInclusive;
+
+/// Search for subsets of items whose values sum to a target
+pub struct SubsetSum<'a> {
+    values: &'a [u64],
+    target: u64,
+    sizes: RangeInclusive<usize>
+}
+
+impl<'a> SubsetSum<'a> {
+    pub fn new(values: &'a [u64], target: u64) -> Self {
+        Self {
+            values,
+            target,
+            sizes: 0..=values.len()
+        }
+    }
+
+    /// Restricts the search to subsets with a number of items in a range
+    pub fn sizes(mut self, sizes: RangeInclusive<usize>) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Calls a function for each matching subset. Subsets are generated in
+    /// lexicographic order of the items sorted by value
+    pub fn for_each<F: FnMut(&BitSet)>(&self, mut f: F) {
+        let walk = self.walk();
+        let mut chosen = BitSet::new(self.values.len());
+
+        if self.target == 0 && self.sizes.contains(&0) {
+            f(&chosen);
+        }
+
+        walk.walk(0, 0, 0, &mut chosen, &mut f);
+    }
+
+    /// Returns all matching subsets
+    pub fn subsets(&self) -> Vec<BitSet> {
+        let mut subsets = Vec::new();
+
+        self.for_each(|s| subsets.push(s.clone()));
+
+        subsets
+    }
+
+    /// Counts the matching subsets
+    pub fn count(&self) -> usize {
+        let mut count = 0;
+
+        self.for_each(|_| count += 1);
+
+        count
+    }
+
+    /// Returns true if there is at least one matching subset
+    pub fn any(&self) -> bool {
+        (self.target == 0 && self.sizes.contains(&0)) || self.walk().find(0, 0, 0)
+    }
+
+    /// Returns the smallest number of items in a matching subset, if any
+    pub fn min_size(&self) -> Option<usize> {
+        self.sizes.clone().find(|&size| {
+            SubsetSum::new(self.values, self.target).sizes(size..=size).any()
+        })
+    }
+
+    fn walk(&self) -> Walk<'_> {
+        // Sort the item indices by value so the search can stop early
+        let mut order: Vec<usize> = (0..self.values.len()).collect();
+        order.sort_by_key(|&i| self.values[i]);
+
+        Walk {
+            values: self.values,
+            order,
+            target: self.target,
+            max_size: *self.sizes.end(),
+            sizes: &self.sizes
+        }
+    }
+}
+
+struct Walk<'a> {
+    values: &'a [u64],
+    order: Vec<usize>,
+    target: u64,
+    max_size: usize,
+    sizes: &'a RangeInclusive<usize>
+}
+
+impl<'a> Walk<'a> {
+    fn walk<F: FnMut(&BitSet)>(&self, start: usize, sum: u64, size: usize, chosen: &mut BitSet, f: &mut F) {
+        if size >= self.max_size {
+            return
+        }
+
+        for pos in start..self.order.len() {
+            let item = self.order[pos];
+            let next_sum = sum + self.values[item];
+
+            // Items are in ascending order so no later item can fit either
+            if next_sum > self.target {
+                break
+            }
+
+            chosen.insert(item);
+
+            if next_sum == self.target && self.sizes.contains(&(size + 1)) {
+                f(chosen);
+            }
+
+            self.walk(pos + 1, next_sum, size + 1, chosen, f);
+
+            chosen.remove(item);
+        }
+    }
+
+    fn find(&self, start: usize, sum: u64, size: usize) -> bool {
+        if size >= self.max_size {
+            return false
+        }
+
+        for pos in start..self.order.len() {
+            let next_sum = sum + self.values[self.order[pos]];
+
+            if next_sum > self.target {
+                break
+            }
+
+            if (next_sum == self.target && self.sizes.contains(&(size + 1))) || self.find(pos + 1, next_sum, size + 1) {
+                return true
+            }
+        }
+
+        false
+    }
+}
+
+#[test]
+fn test_subset_sum() {
+    let values = [5, 5, 10, 15, 20];
+
+    let subsets = SubsetSum::new(&values, 25).subsets();
+    let lists: Vec<String> = subsets.iter().map(|s| s.join(&values, "+")).collect();
+    assert!(lists == vec!["5+5+15", "5+20", "5+20", "10+15"]);
+
+    assert!(SubsetSum::new(&values, 25).count() == 4);
+    assert!(SubsetSum::new(&values, 25).sizes(2..=2).count() == 3);
+    assert!(SubsetSum::new(&values, 25).min_size() == Some(2));
+    assert!(SubsetSum::new(&values, 100).min_size().is_none());
+    assert!(SubsetSum::new(&values, 0).count() == 1);
+}
+
+#[test]
+fn test_many_items() {
+    // More items than fit in a machine word
+    let mut values = vec![1000; 70];
+    values.push(1);
+    values.push(2);
+
+    let subsets = SubsetSum::new(&values, 3).subsets();
+    assert!(subsets.len() == 1 && subsets[0].iter().collect::<Vec<_>>() == vec![70, 71]);
+
+    assert!(SubsetSum::new(&values, 1001).count() == 70);
+    assert!(SubsetSum::new(&values, 2001).sizes(3..=3).count() == 70 * 69 / 2);
+}
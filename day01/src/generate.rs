@@ -0,0 +1,12 @@
+//! Random floor directions
+
+use aoc_lib::Rng;
+
+/// Generates a string of size brackets, slightly biased towards going up so
+/// the basement is reached part way through
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text: String = (0..size.max(1)).map(|_| if rng.chance(0.52) { '(' } else { ')' }).collect();
+
+    text.push('\n');
+    text
+}
@@ -0,0 +1,54 @@
+pub mod generate;
+pub mod trace;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult, Solution, Validation};
+use std::error::Error;
+use trace::trace;
+
+pub const DAY: Day = Day {
+    day: 1,
+    input: Some("input01.txt"),
+    parts: &[
+        Part { part: 1, desc: "End floor", solve: part1 },
+        Part { part: 2, desc: "Basement position", solve: part2 },
+    ],
+    params: &[
+        Param { name: "validation", desc: "Handling of other characters: strict or lenient (default)" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let trace = trace(directions(input)?);
+
+    Ok(Solution::from(trace.floor())
+        .with_detail("lowest", trace.lowest())
+        .with_detail("highest", trace.highest()))
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let basement_pos = trace(directions(input)?).first_hit(-1).unwrap_or(0);
+
+    Ok(basement_pos.into())
+}
+
+/// Returns the directions once every other character has been checked
+fn directions(input: &Input) -> Result<&str, Box<dyn Error>> {
+    let validation = input.param_or("validation", Validation::Lenient)?;
+
+    validation.check(input.text(), |b| b == b'(' || b == b')', "( or )")?;
+
+    Ok(input.text())
+}
+
+#[test]
+fn test_validation() {
+    let mut input = Input::from("((( )\n");
+    assert!(part1(&input).unwrap().answer == 2.into());
+
+    input.set_param("validation", "strict");
+    assert!(part1(&input).unwrap_err().to_string() == "1 invalid bytes, expected ( or ): ' ' at 4");
+
+    let input = Input::from("(x)))\n");
+    assert!(part2(&input).is_err());
+}
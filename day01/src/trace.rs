@@ -0,0 +1,233 @@
+//! Floor traces over directions of any length
+
+use std::io::{self, Read};
+
+/// Number of directions stepped over at once when no query can be affected
+const CHUNK: usize = 64;
+
+/// Size of the buffer used when reading directions from a reader
+const READ_BUFFER: usize = 64 * 1024;
+
+/// A step onto a watched floor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crossing {
+    /// Position of the direction which reached the floor, from 1
+    pub pos: u64,
+    /// True if the floor was reached going up
+    pub up: bool
+}
+
+/// A floor whose crossings are recorded
+#[derive(Debug, Clone)]
+struct Watch {
+    floor: i64,
+    crossings: Vec<Crossing>
+}
+
+/// Follows directions a block at a time, keeping the current, lowest and
+/// highest floors and the first position of every floor reached. Only the
+/// watched floors record all of their crossings, so the directions never have
+/// to be held in memory
+#[derive(Debug, Clone, Default)]
+pub struct FloorTrace {
+    floor: i64,
+    pos: u64,
+    lowest: i64,
+    highest: i64,
+    /// Position each floor above the ground is first reached, from floor 1
+    first_up: Vec<u64>,
+    /// Position each floor below the ground is first reached, from floor -1
+    first_down: Vec<u64>,
+    watches: Vec<Watch>
+}
+
+impl FloorTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records every crossing of a floor from now on
+    pub fn watch(mut self, floor: i64) -> Self {
+        if !self.watches.iter().any(|w| w.floor == floor) {
+            self.watches.push(Watch { floor, crossings: Vec::new() });
+        }
+
+        self
+    }
+
+    /// Follows a block of directions. '(' goes up, ')' goes down and any
+    /// other byte only moves the position on
+    pub fn feed(&mut self, directions: &[u8]) {
+        for chunk in directions.chunks(CHUNK) {
+            if self.can_skip(chunk.len() as i64) {
+                self.floor += net_floors(chunk);
+                self.pos += chunk.len() as u64;
+            } else {
+                chunk.iter().for_each(|&b| self.step(b));
+            }
+        }
+    }
+
+    /// Follows all of the directions from a reader
+    pub fn feed_reader<R: Read>(&mut self, mut reader: R) -> io::Result<()> {
+        let mut buffer = vec![0; READ_BUFFER];
+
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => return Ok(()),
+                Ok(n) => self.feed(&buffer[..n]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => return Err(e)
+            }
+        }
+    }
+
+    /// Returns the current floor
+    pub fn floor(&self) -> i64 {
+        self.floor
+    }
+
+    /// Returns the number of directions followed
+    pub fn len(&self) -> u64 {
+        self.pos
+    }
+
+    /// Returns true if no directions have been followed
+    pub fn is_empty(&self) -> bool {
+        self.pos == 0
+    }
+
+    /// Returns the lowest floor reached
+    pub fn lowest(&self) -> i64 {
+        self.lowest
+    }
+
+    /// Returns the highest floor reached
+    pub fn highest(&self) -> i64 {
+        self.highest
+    }
+
+    /// Returns the position at which a floor was first reached, or 0 for the
+    /// ground floor
+    pub fn first_hit(&self, floor: i64) -> Option<u64> {
+        let index = floor.unsigned_abs() as usize;
+
+        match floor {
+            0 => Some(0),
+            f if f > 0 => self.first_up.get(index - 1).copied(),
+            _ => self.first_down.get(index - 1).copied()
+        }
+    }
+
+    /// Returns the crossings of a watched floor, or None if the floor is not
+    /// watched
+    pub fn crossings(&self, floor: i64) -> Option<&[Crossing]> {
+        self.watches.iter().find(|w| w.floor == floor).map(|w| w.crossings.as_slice())
+    }
+
+    fn step(&mut self, b: u8) {
+        self.pos += 1;
+
+        let up = match b {
+            b'(' => true,
+            b')' => false,
+            _ => return
+        };
+
+        self.floor += if up { 1 } else { -1 };
+
+        if self.floor > self.highest {
+            self.highest = self.floor;
+            self.first_up.push(self.pos);
+        } else if self.floor < self.lowest {
+            self.lowest = self.floor;
+            self.first_down.push(self.pos);
+        }
+
+        let (floor, pos) = (self.floor, self.pos);
+
+        for watch in self.watches.iter_mut().filter(|w| w.floor == floor) {
+            watch.crossings.push(Crossing { pos, up });
+        }
+    }
+
+    /// Returns true if a chunk of directions can be summed without stepping
+    /// through it, because it is too short to reach a new lowest or highest
+    /// floor or any watched floor
+    fn can_skip(&self, len: i64) -> bool {
+        let (lo, hi) = (self.floor - len, self.floor + len);
+
+        lo > self.lowest && hi < self.highest && self.watches.iter().all(|w| w.floor < lo || w.floor > hi)
+    }
+}
+
+/// Returns the change in floor over a block of directions. Written as a
+/// branch free sum so that it is vectorised
+fn net_floors(directions: &[u8]) -> i64 {
+    directions.iter().map(|&b| (b == b'(') as i64 - (b == b')') as i64).sum()
+}
+
+/// Returns the floor trace of a string of directions
+pub fn trace(directions: &str) -> FloorTrace {
+    let mut trace = FloorTrace::new();
+    trace.feed(directions.as_bytes());
+
+    trace
+}
+
+#[test]
+fn test_trace() {
+    let trace = trace("(()))");
+    assert!(trace.floor() == -1 && trace.len() == 5);
+    assert!(trace.lowest() == -1 && trace.highest() == 2);
+    assert!(trace.first_hit(0) == Some(0));
+    assert!(trace.first_hit(1) == Some(1) && trace.first_hit(2) == Some(2));
+    assert!(trace.first_hit(-1) == Some(5) && trace.first_hit(-2).is_none() && trace.first_hit(3).is_none());
+
+    assert!(self::trace("()())").first_hit(-1) == Some(5));
+    assert!(self::trace(")())())").floor() == -3);
+}
+
+#[test]
+fn test_crossings() {
+    let mut trace = FloorTrace::new().watch(1).watch(-1);
+    trace.feed(b"(()))((\n");
+
+    let up = |pos| Crossing { pos, up: true };
+    let down = |pos| Crossing { pos, up: false };
+
+    assert!(trace.crossings(1).unwrap() == [up(1), down(3), up(7)]);
+    assert!(trace.crossings(-1).unwrap() == [down(5)]);
+    assert!(trace.crossings(0).is_none());
+}
+
+#[test]
+fn test_chunked() {
+    // Long climbs and falls so that whole chunks are skipped, checked against
+    // stepping through one direction at a time
+    let mut directions = String::new();
+
+    for i in 0..40 {
+        directions.push_str(&"(".repeat(200 + i));
+        directions.push_str(&")(".repeat(50));
+        directions.push_str(&")".repeat(300 - i));
+    }
+
+    let mut chunked = FloorTrace::new().watch(-150);
+    chunked.feed(directions.as_bytes());
+
+    let mut stepped = FloorTrace::new().watch(-150);
+
+    for b in directions.bytes() {
+        stepped.step(b);
+    }
+
+    assert!(chunked.floor() == stepped.floor() && chunked.len() == stepped.len());
+    assert!(chunked.lowest() == stepped.lowest() && chunked.highest() == stepped.highest());
+    assert!((chunked.lowest()..=chunked.highest()).all(|f| chunked.first_hit(f) == stepped.first_hit(f)));
+    assert!(chunked.crossings(-150).unwrap().len() > 1 && chunked.crossings(-150) == stepped.crossings(-150));
+
+    let mut read = FloorTrace::new();
+    read.feed_reader(directions.as_bytes()).unwrap();
+    assert!(read.floor() == chunked.floor() && read.first_hit(-100) == chunked.first_hit(-100));
+}
@@ -0,0 +1,14 @@
+//! Random present dimensions
+
+use aoc_lib::Rng;
+
+/// Generates size lines of present dimensions
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for _ in 0..size.max(1) {
+        text += &format!("{}x{}x{}\n", rng.range(1..=30), rng.range(1..=30), rng.range(1..=30));
+    }
+
+    text
+}
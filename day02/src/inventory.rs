@@ -0,0 +1,432 @@
+//! Parcel lists in text, CSV and JSON formats, and costed order reports
+
+use crate::{rules::WrappingRule, shape::Shape, Parcel};
+use aoc_lib::{Line, ParseError};
+use json::{object, JsonValue};
+use std::{convert::TryInto, error::Error, path::Path, str::FromStr};
+
+/// Column and field names accepted for each value, in the order written
+const LENGTH: &[&str] = &["l", "length"];
+const WIDTH: &[&str] = &["w", "width"];
+const HEIGHT: &[&str] = &["h", "height"];
+const SIDE: &[&str] = &["side", "l", "length"];
+const RADIUS: &[&str] = &["radius", "r"];
+const QUANTITY: &[&str] = &["quantity", "qty"];
+const SHAPE: &[&str] = &["shape"];
+
+/// Report columns, in order
+const REPORT_COLUMNS: &[&str] = &[
+    "shape", "quantity", "paper_each", "ribbon_each", "paper", "ribbon", "paper_cost", "ribbon_cost", "cost"
+];
+
+/// Format of a parcel list or order report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One shape per line, such as "LxWxH"
+    Text,
+    /// A header row naming the columns, then one row per order line. Boxes
+    /// need l, w and h, cubes need a side and cylinders a radius and h.
+    /// The shape and quantity columns are optional
+    Csv,
+    /// An array of objects with the same fields as the CSV columns
+    Json
+}
+
+impl Format {
+    /// Guesses the format of a parcel list from its content
+    pub fn detect(text: &str) -> Self {
+        let text = text.trim_start();
+
+        if text.starts_with('[') {
+            Format::Json
+        } else if text.lines().next().is_some_and(|l| l.contains(',')) {
+            Format::Csv
+        } else {
+            Format::Text
+        }
+    }
+
+    /// Returns the format for a file name's extension, if there is one
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()?.to_str()?.parse().ok()
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Ok(Format::Text),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err("expected text, csv or json".to_string())
+        }
+    }
+}
+
+/// A number of identical presents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub shape: Shape,
+    pub quantity: u64
+}
+
+/// Parses a parcel list in a format
+pub fn parse_items(text: &str, format: Format) -> Result<Vec<Item>, Box<dyn Error>> {
+    match format {
+        Format::Text => parse_text(text).map_err(|e| e.into()),
+        Format::Csv => parse_csv(text).map_err(|e| e.into()),
+        Format::Json => parse_json(text)
+    }
+}
+
+fn parse_text(text: &str) -> Result<Vec<Item>, ParseError> {
+    numbered_lines(text)
+        .map(|line| Ok(Item { shape: line.parse(line.text.trim(), "LxWxH, cube S or cylinder RxH")?, quantity: 1 }))
+        .collect()
+}
+
+fn parse_csv(text: &str) -> Result<Vec<Item>, ParseError> {
+    let mut lines = numbered_lines(text);
+
+    let header = lines.next().ok_or_else(|| Line::new(1, "").line_error("a header row"))?;
+    let names: Vec<String> = header.text.split(',').map(|c| c.trim().to_ascii_lowercase()).collect();
+
+    let column = |aliases: &[&str]| aliases.iter().find_map(|a| names.iter().position(|n| n == a));
+
+    if column(LENGTH).is_none() && column(SIDE).is_none() && column(RADIUS).is_none() {
+        return Err(header.line_error("a header naming the l, w and h columns"))
+    }
+
+    lines.map(|line| {
+        let cells: Vec<&str> = line.text.split(',').map(|c| c.trim()).collect();
+
+        if cells.len() != names.len() {
+            return Err(line.line_error(&format!("{} columns", names.len())))
+        }
+
+        // Empty cells are missing values
+        let cell = |aliases: &[&str]| column(aliases).map(|c| cells[c]).filter(|c| !c.is_empty());
+        let value = |aliases: &[&str]| cell(aliases)
+            .map(|c| line.parse(c, &format!("a whole number {}", aliases[0])))
+            .transpose();
+
+        Ok(Item {
+            shape: shape_from_fields(cell(SHAPE), value, |e| line.line_error(&e))?,
+            quantity: value(QUANTITY)?.unwrap_or(1)
+        })
+    }).collect()
+}
+
+fn parse_json(text: &str) -> Result<Vec<Item>, Box<dyn Error>> {
+    let list = json::parse(text)?;
+
+    if !list.is_array() {
+        return Err("expected an array of parcels".into())
+    }
+
+    list.members().enumerate().map(|(idx, entry)| {
+        let error = |e: String| format!("parcel {}: expected {}", idx + 1, e);
+
+        let field = |aliases: &[&str]| aliases.iter().map(|a| &entry[*a]).find(|v| !v.is_null());
+        let value = |aliases: &[&str]| field(aliases)
+            .map(|v| v.as_u64().ok_or_else(|| error(format!("a whole number {}", aliases[0]))))
+            .transpose();
+
+        Ok(Item {
+            shape: shape_from_fields(field(SHAPE).and_then(|v| v.as_str()), value, error)?,
+            quantity: value(QUANTITY)?.unwrap_or(1)
+        })
+    }).collect::<Result<_, String>>().map_err(|e| e.into())
+}
+
+/// Builds a shape from the values of a CSV row or JSON object. A missing shape
+/// is a box
+fn shape_from_fields<E, V, F>(shape: Option<&str>, value: V, error: F) -> Result<Shape, E>
+where
+    V: Fn(&[&str]) -> Result<Option<u64>, E>,
+    F: Fn(String) -> E
+{
+    let length = |aliases: &[&str]| -> Result<u32, E> {
+        value(aliases)?
+            .ok_or_else(|| error(format!("a value for {}", aliases[0])))?
+            .try_into().map_err(|_| error(format!("a {} below 2^32", aliases[0])))
+    };
+
+    match shape.unwrap_or("box") {
+        "box" => Ok(Shape::Cuboid(Parcel { l: length(LENGTH)?, w: length(WIDTH)?, h: length(HEIGHT)? })),
+        "cube" => Ok(Shape::Cube(length(SIDE)?)),
+        "cylinder" => Ok(Shape::Cylinder { radius: length(RADIUS)?, height: length(HEIGHT)? }),
+        _ => Err(error("a shape of box, cube or cylinder".to_string()))
+    }
+}
+
+fn numbered_lines(text: &str) -> impl Iterator<Item = Line<'_>> {
+    text.lines().enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(idx, l)| Line::new(idx + 1, l))
+}
+
+/// Paper and ribbon in square feet and feet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Wrapping {
+    pub paper: u64,
+    pub ribbon: u64
+}
+
+impl Wrapping {
+    /// Returns the wrapping needed for a shape under a rule, or None on
+    /// overflow
+    pub fn for_shape(shape: &Shape, rule: &dyn WrappingRule) -> Option<Self> {
+        Some(Self {
+            paper: rule.paper(shape)?,
+            ribbon: rule.ribbon(shape)?
+        })
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self {
+            paper: self.paper.checked_add(other.paper)?,
+            ribbon: self.ribbon.checked_add(other.ribbon)?
+        })
+    }
+
+    pub fn checked_mul(self, n: u64) -> Option<Self> {
+        Some(Self {
+            paper: self.paper.checked_mul(n)?,
+            ribbon: self.ribbon.checked_mul(n)?
+        })
+    }
+}
+
+/// Returns the wrapping needed for a whole parcel list under a rule
+pub fn total_wrapping(items: &[Item], rule: &dyn WrappingRule) -> Result<Wrapping, String> {
+    items.iter().enumerate().try_fold(Wrapping::default(), |total, (idx, item)| {
+        Wrapping::for_shape(&item.shape, rule)
+            .and_then(|each| each.checked_mul(item.quantity))
+            .and_then(|line| total.checked_add(line))
+            .ok_or_else(|| format!("parcel {}: wrapping needed is too large", idx + 1))
+    })
+}
+
+/// Prices of a square foot of paper and a foot of ribbon, in the smallest
+/// unit of currency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prices {
+    pub paper: u64,
+    pub ribbon: u64
+}
+
+/// One costed line of an order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportLine {
+    pub item: Item,
+    /// Wrapping for one parcel
+    pub each: Wrapping,
+    /// Wrapping for every parcel on the line
+    pub wrapping: Wrapping,
+    pub paper_cost: u64,
+    pub ribbon_cost: u64
+}
+
+impl ReportLine {
+    pub fn cost(&self) -> u64 {
+        self.paper_cost + self.ribbon_cost
+    }
+}
+
+/// A costed order for a parcel list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderReport {
+    pub lines: Vec<ReportLine>,
+    pub wrapping: Wrapping,
+    pub paper_cost: u64,
+    pub ribbon_cost: u64
+}
+
+impl OrderReport {
+    /// Costs a parcel list under a wrapping rule, failing if any amount
+    /// overflows
+    pub fn new(items: &[Item], prices: Prices, rule: &dyn WrappingRule) -> Result<Self, String> {
+        let mut report = OrderReport {
+            lines: Vec::with_capacity(items.len()),
+            wrapping: Wrapping::default(),
+            paper_cost: 0,
+            ribbon_cost: 0
+        };
+
+        for (idx, item) in items.iter().enumerate() {
+            let line = Self::line(item, prices, rule)
+                .ok_or_else(|| format!("parcel {}: order line is too large", idx + 1))?;
+
+            report.wrapping = report.wrapping.checked_add(line.wrapping)
+                .ok_or_else(|| format!("parcel {}: total wrapping is too large", idx + 1))?;
+
+            match (report.paper_cost.checked_add(line.paper_cost), report.ribbon_cost.checked_add(line.ribbon_cost)) {
+                (Some(paper), Some(ribbon)) if paper.checked_add(ribbon).is_some() => {
+                    report.paper_cost = paper;
+                    report.ribbon_cost = ribbon;
+                }
+                _ => return Err(format!("parcel {}: total cost is too large", idx + 1))
+            }
+
+            report.lines.push(line);
+        }
+
+        Ok(report)
+    }
+
+    fn line(item: &Item, prices: Prices, rule: &dyn WrappingRule) -> Option<ReportLine> {
+        let each = Wrapping::for_shape(&item.shape, rule)?;
+        let wrapping = each.checked_mul(item.quantity)?;
+        let paper_cost = wrapping.paper.checked_mul(prices.paper)?;
+        let ribbon_cost = wrapping.ribbon.checked_mul(prices.ribbon)?;
+
+        paper_cost.checked_add(ribbon_cost)?;
+
+        Some(ReportLine { item: *item, each, wrapping, paper_cost, ribbon_cost })
+    }
+
+    /// Returns the total cost of the order
+    pub fn cost(&self) -> u64 {
+        self.paper_cost + self.ribbon_cost
+    }
+
+    /// Writes the report as CSV, with a final totals row which has an empty
+    /// shape column
+    pub fn to_csv(&self) -> String {
+        let mut csv = REPORT_COLUMNS.join(",") + "\n";
+
+        for line in &self.lines {
+            csv += &format!("{},{},{},{},{},{},{},{},{}\n", line.item.shape, line.item.quantity,
+                line.each.paper, line.each.ribbon, line.wrapping.paper, line.wrapping.ribbon,
+                line.paper_cost, line.ribbon_cost, line.cost());
+        }
+
+        csv += &format!(",{},,,{},{},{},{},{}\n", self.parcels(), self.wrapping.paper, self.wrapping.ribbon,
+            self.paper_cost, self.ribbon_cost, self.cost());
+
+        csv
+    }
+
+    /// Returns the report as a JSON object holding the order lines and totals
+    pub fn to_json(&self) -> JsonValue {
+        let lines: Vec<JsonValue> = self.lines.iter().map(|line| object! {
+            shape: line.item.shape.to_string(),
+            quantity: line.item.quantity,
+            paper_each: line.each.paper,
+            ribbon_each: line.each.ribbon,
+            paper: line.wrapping.paper,
+            ribbon: line.wrapping.ribbon,
+            paper_cost: line.paper_cost,
+            ribbon_cost: line.ribbon_cost,
+            cost: line.cost()
+        }).collect();
+
+        object! {
+            lines: lines,
+            total: object! {
+                quantity: self.parcels(),
+                paper: self.wrapping.paper,
+                ribbon: self.wrapping.ribbon,
+                paper_cost: self.paper_cost,
+                ribbon_cost: self.ribbon_cost,
+                cost: self.cost()
+            }
+        }
+    }
+
+    /// Writes the report in a format. Text is written as CSV
+    pub fn export(&self, format: Format) -> String {
+        match format {
+            Format::Text | Format::Csv => self.to_csv(),
+            Format::Json => self.to_json().pretty(2) + "\n"
+        }
+    }
+
+    /// Returns the number of parcels ordered, saturating on overflow
+    fn parcels(&self) -> u64 {
+        self.lines.iter().fold(0u64, |n, line| n.saturating_add(line.item.quantity))
+    }
+}
+
+#[test]
+fn test_parse_items() {
+    let parcel = |l, w, h| Shape::Cuboid(Parcel { l, w, h });
+
+    let text = parse_items("2x3x4\n\n1x1x10\n", Format::Text).unwrap();
+    assert!(text == vec![Item { shape: parcel(2, 3, 4), quantity: 1 }, Item { shape: parcel(1, 1, 10), quantity: 1 }]);
+
+    let csv = parse_items("Height, Width,Length,Qty\n4,3,2,5\n10, 1, 1, 2\n", Format::Csv).unwrap();
+    assert!(csv == vec![Item { shape: parcel(2, 3, 4), quantity: 5 }, Item { shape: parcel(1, 1, 10), quantity: 2 }]);
+
+    let csv = parse_items("l,w,h\n2,3,4\n", Format::Csv).unwrap();
+    assert!(csv == vec![Item { shape: parcel(2, 3, 4), quantity: 1 }]);
+
+    let json = parse_items(r#"[{"l": 2, "w": 3, "h": 4, "quantity": 5}, {"length": 1, "width": 1, "height": 10}]"#, Format::Json).unwrap();
+    assert!(json == vec![Item { shape: parcel(2, 3, 4), quantity: 5 }, Item { shape: parcel(1, 1, 10), quantity: 1 }]);
+
+    let err = parse_items("l,w,h\n2,3,4\n2,x,4\n", Format::Csv).unwrap_err();
+    assert!(err.to_string() == "line 3, column 3: expected a whole number w, found \"x\"");
+
+    let csv = parse_items("shape,side,radius,h\ncube,3,,\ncylinder,,2,5\n,,,\n", Format::Csv);
+    assert!(csv.unwrap_err().to_string() == "line 4, column 1: expected a value for l, found \",,,\"");
+
+    let csv = parse_items("shape,side,radius,h,l,w\ncube,3,,,,\ncylinder,,2,5,,\nbox,,,4,2,3\n", Format::Csv).unwrap();
+    assert!(csv.iter().map(|i| i.shape).collect::<Vec<_>>()
+        == vec![Shape::Cube(3), Shape::Cylinder { radius: 2, height: 5 }, parcel(2, 3, 4)]);
+
+    let json = parse_items(r#"[{"shape": "cube", "l": 3}, {"shape": "cylinder", "r": 2, "h": 5, "qty": 4}]"#, Format::Json).unwrap();
+    assert!(json == vec![Item { shape: Shape::Cube(3), quantity: 1 }, Item { shape: Shape::Cylinder { radius: 2, height: 5 }, quantity: 4 }]);
+
+    let text = parse_items("cube 3\ncylinder 2x5\n", Format::Text).unwrap();
+    assert!(text[0].shape == Shape::Cube(3) && text[1].shape == Shape::Cylinder { radius: 2, height: 5 });
+
+    assert!(parse_items(r#"[{"shape": "sphere", "r": 2}]"#, Format::Json).is_err());
+    assert!(parse_items("l,w\n2,3\n", Format::Csv).is_err());
+    assert!(parse_items("w,h\n2,3\n", Format::Csv).is_err());
+    assert!(parse_items("l,w,h\n2,3\n", Format::Csv).is_err());
+    assert!(parse_items(r#"[{"l": 2, "w": 3}]"#, Format::Json).is_err());
+    assert!(parse_items(r#"[{"l": 2, "w": 3, "h": 5000000000}]"#, Format::Json).is_err());
+
+    assert!(Format::detect(" [\n") == Format::Json);
+    assert!(Format::detect("l,w,h\n") == Format::Csv);
+    assert!(Format::detect("2x3x4\n") == Format::Text);
+    assert!(Format::from_path(Path::new("order.CSV")) == Some(Format::Csv));
+}
+
+#[test]
+fn test_order_report() {
+    use crate::rules::{Bow, HouseRule, Slack};
+
+    let items = parse_items("l,w,h,quantity\n2,3,4,5\n1,1,10,2\n", Format::Csv).unwrap();
+
+    let elves = HouseRule::default();
+
+    assert!(total_wrapping(&items, &elves) == Ok(Wrapping { paper: 58 * 5 + 43 * 2, ribbon: 34 * 5 + 14 * 2 }));
+
+    let report = OrderReport::new(&items, Prices { paper: 2, ribbon: 3 }, &elves).unwrap();
+    assert!(report.paper_cost == 752 && report.ribbon_cost == 594 && report.cost() == 1346);
+
+    assert!(report.to_csv() == "shape,quantity,paper_each,ribbon_each,paper,ribbon,paper_cost,ribbon_cost,cost\n\
+        2x3x4,5,58,34,290,170,580,510,1090\n\
+        1x1x10,2,43,14,86,28,172,84,256\n\
+        ,7,,,376,198,752,594,1346\n");
+
+    let json = report.to_json();
+    assert!(json["lines"][1]["cost"] == 256 && json["total"]["cost"] == 1346);
+
+    // Amounts which do not fit in 64 bits
+    let big = Item { shape: Shape::Cube(u32::MAX), quantity: 1 };
+    assert!(total_wrapping(&[big], &elves).is_err());
+
+    let many = Item { shape: Shape::Cube(1), quantity: u64::MAX / 2 };
+    assert!(total_wrapping(&[many], &elves).is_err());
+    assert!(OrderReport::new(&items, Prices { paper: u64::MAX / 300, ribbon: 1 }, &elves).is_err());
+
+    // Other house rules and shapes
+    let plain = HouseRule { slack: Slack::None, bow: Bow::Fixed(1) };
+    let items = parse_items("cube 3\ncylinder 2x5\n", Format::Text).unwrap();
+    assert!(total_wrapping(&items, &plain) == Ok(Wrapping { paper: 54 + 88, ribbon: 12 + 1 + 13 + 1 }));
+}
@@ -0,0 +1,185 @@
+pub mod generate;
+pub mod inventory;
+pub mod rules;
+pub mod shape;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult, Solution};
+use inventory::{parse_items, total_wrapping, Format, Item, OrderReport, Prices};
+use rules::{HouseRule, WrappingRule};
+use shape::Shape;
+use std::{error::Error, fs, path::PathBuf, str::FromStr};
+
+pub const DAY: Day = Day {
+    day: 2,
+    input: Some("input02.txt"),
+    parts: &[
+        Part { part: 1, desc: "Total paper", solve: part1 },
+        Part { part: 2, desc: "Total ribbon", solve: part2 },
+    ],
+    params: &[
+        Param { name: "format", desc: "Parcel list format: text, csv or json (default from the content)" },
+        Param { name: "paper_price", desc: "Price of a square foot of paper (default 1) in the order report" },
+        Param { name: "ribbon_price", desc: "Price of a foot of ribbon (default 1) in the order report" },
+        Param { name: "report", desc: "File to write the costed order report to (.csv or .json), adding the costs to either part" },
+        Param { name: "slack", desc: "Extra paper: smallest (default), none or a percentage such as 5%" },
+        Param { name: "bow", desc: "Ribbon for the bow: volume (default), none or a length in feet" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let items = load_items(input)?;
+
+    let paper = total_wrapping(&items, &house_rule(input)?)?.paper;
+
+    with_order_report(input, &items, paper.into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let items = load_items(input)?;
+
+    let ribbon = total_wrapping(&items, &house_rule(input)?)?.ribbon;
+
+    with_order_report(input, &items, ribbon.into())
+}
+
+/// Writes the costed order report if a report file is given, adding the costs
+/// to the solution. Both parts write the same report so either can be run
+/// on its own
+fn with_order_report(input: &Input, items: &[Item], solution: Solution) -> PartResult {
+    let file = match input.param::<PathBuf>("report")? {
+        Some(file) => file,
+        None => return Ok(solution)
+    };
+
+    let format = Format::from_path(&file)
+        .ok_or_else(|| format!("{}: expected a .csv or .json report file", file.display()))?;
+
+    let prices = Prices {
+        paper: input.param_or("paper_price", 1)?,
+        ribbon: input.param_or("ribbon_price", 1)?
+    };
+
+    let report = OrderReport::new(items, prices, &house_rule(input)?)?;
+
+    fs::write(&file, report.export(format)).map_err(|e| format!("{}: {}", file.display(), e))?;
+
+    Ok(solution
+        .with_detail("paper_cost", report.paper_cost)
+        .with_detail("ribbon_cost", report.ribbon_cost)
+        .with_detail("order_cost", report.cost()))
+}
+
+/// Returns the wrapping rule, which is the elves' rule unless changed
+fn house_rule(input: &Input) -> Result<HouseRule, Box<dyn Error>> {
+    let default = HouseRule::default();
+
+    Ok(HouseRule {
+        slack: input.param_or("slack", default.slack)?,
+        bow: input.param_or("bow", default.bow)?
+    })
+}
+
+/// Parses the parcel list in the format given, or the format detected
+fn load_items(input: &Input) -> Result<Vec<Item>, Box<dyn Error>> {
+    let format = match input.param::<Format>("format")? {
+        Some(format) => format,
+        None => Format::detect(input.text())
+    };
+
+    parse_items(input.text(), format)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parcel {
+    pub l: u32,
+    pub h: u32,
+    pub w: u32
+}
+
+impl Parcel {
+
+    /// Returns the paper needed in square feet under the elves' rule, or None
+    /// on overflow
+    pub fn paper(&self) -> Option<u64> {
+        HouseRule::default().paper(&Shape::Cuboid(*self))
+    }
+
+    /// Returns the ribbon needed in feet under the elves' rule, or None on
+    /// overflow
+    pub fn ribbon(&self) -> Option<u64> {
+        HouseRule::default().ribbon(&Shape::Cuboid(*self))
+    }
+
+}
+
+impl FromStr for Parcel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dim: Vec<u32> = s.split('x')
+            .map(|val| val.parse::<u32>().map_err(|e| e.to_string()))
+            .collect::<Result<_, _>>()?;
+
+        if dim.len() != 3 {
+            return Err("expected LxWxH".to_string())
+        }
+
+        Ok(Parcel {
+            l: dim[0],
+            w: dim[1],
+            h: dim[2]
+        })
+    }
+}
+
+#[test]
+fn test_parcel() {
+    let p1 = Parcel {
+        l: 2,
+        h: 3,
+        w: 4
+    };
+
+    let paper1 = p1.paper().unwrap();
+    assert!(paper1 == 58, "Paper should be 58 (not {})", paper1);
+
+    let ribbon1 = p1.ribbon().unwrap();
+    assert!(ribbon1 == 34, "Ribbon should be 34 (not {})", ribbon1);
+
+    let p2 = Parcel {
+        l: 1,
+        h: 1,
+        w: 10
+    };
+
+    let paper2 = p2.paper().unwrap();
+    assert!(paper2 == 43, "Paper should be 43 (not {})", paper2);
+
+    let ribbon2 = p2.ribbon().unwrap();
+    assert!(ribbon2 == 14, "Ribbon should be 14 (not {})", ribbon2);
+}
+
+#[test]
+fn test_order_report_param() {
+    let mut input = Input::from("2x3x4\n1x1x10\n");
+    assert!(part1(&input).unwrap().detail.is_empty());
+
+    let file = std::env::temp_dir().join(format!("day02-report-{}.csv", std::process::id()));
+    input.set_param("report", file.to_str().unwrap());
+    input.set_param("ribbon_price", "2");
+
+    let paper = part1(&input).unwrap();
+    assert!(paper.answer == 101u64.into() && paper.detail[0] == ("paper_cost", 101.into()));
+    assert!(paper.detail[2] == ("order_cost", 197.into()));
+
+    let ribbon = part2(&input).unwrap();
+    assert!(ribbon.answer == 48u64.into() && ribbon.detail == paper.detail);
+
+    let csv = fs::read_to_string(&file).unwrap();
+    fs::remove_file(&file).unwrap();
+    assert!(csv.starts_with("shape,quantity,") && csv.lines().count() == 4);
+
+    input.set_param("report", "report.xml");
+    assert!(part1(&input).unwrap_err().to_string() == "report.xml: expected a .csv or .json report file");
+}
@@ -0,0 +1,119 @@
+//! House rules for how much paper and ribbon a present needs
+
+use crate::shape::Shape;
+use std::str::FromStr;
+
+/// A rule for the paper and ribbon needed to wrap a shape. Each returns None
+/// if the amount is too large
+pub trait WrappingRule {
+    /// Returns the paper needed in square feet
+    fn paper(&self, shape: &Shape) -> Option<u64>;
+
+    /// Returns the ribbon needed in feet
+    fn ribbon(&self, shape: &Shape) -> Option<u64>;
+}
+
+/// Extra paper added to the surface of a shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slack {
+    /// The area of the smallest face
+    SmallestFace,
+    /// A percentage of the surface, rounded up
+    Percent(u64),
+    None
+}
+
+/// Ribbon used for the bow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bow {
+    /// As many feet as the volume in cubic feet
+    Volume,
+    /// A fixed length in feet
+    Fixed(u64),
+    None
+}
+
+/// Paper covers the surface plus some slack, and ribbon goes once round the
+/// shortest way plus a bow. The default is the elves' rule, with the smallest
+/// face as slack and a bow as long as the volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HouseRule {
+    pub slack: Slack,
+    pub bow: Bow
+}
+
+impl Default for HouseRule {
+    fn default() -> Self {
+        Self {
+            slack: Slack::SmallestFace,
+            bow: Bow::Volume
+        }
+    }
+}
+
+impl WrappingRule for HouseRule {
+    fn paper(&self, shape: &Shape) -> Option<u64> {
+        let surface = shape.surface()?;
+
+        let slack = match self.slack {
+            Slack::SmallestFace => shape.smallest_face()?,
+            Slack::Percent(p) => surface.checked_mul(p)?.div_ceil(100),
+            Slack::None => 0
+        };
+
+        surface.checked_add(slack)
+    }
+
+    fn ribbon(&self, shape: &Shape) -> Option<u64> {
+        let bow = match self.bow {
+            Bow::Volume => shape.volume()?,
+            Bow::Fixed(len) => len,
+            Bow::None => 0
+        };
+
+        shape.smallest_perimeter()?.checked_add(bow)
+    }
+}
+
+impl FromStr for Slack {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "smallest" => Ok(Slack::SmallestFace),
+            "none" => Ok(Slack::None),
+            _ => s.strip_suffix('%').and_then(|p| p.parse().ok()).map(Slack::Percent)
+                .ok_or_else(|| "expected smallest, none or a percentage such as 5%".to_string())
+        }
+    }
+}
+
+impl FromStr for Bow {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "volume" => Ok(Bow::Volume),
+            "none" => Ok(Bow::None),
+            _ => s.parse().map(Bow::Fixed).map_err(|_| "expected volume, none or a length in feet".to_string())
+        }
+    }
+}
+
+#[test]
+fn test_house_rules() {
+    let parcel: Shape = "2x3x4".parse().unwrap();
+
+    let elves = HouseRule::default();
+    assert!(elves.paper(&parcel) == Some(58) && elves.ribbon(&parcel) == Some(34));
+
+    let plain = HouseRule { slack: "none".parse().unwrap(), bow: "none".parse().unwrap() };
+    assert!(plain.paper(&parcel) == Some(52) && plain.ribbon(&parcel) == Some(10));
+
+    // 10% of 52 is 5.2, rounded up
+    let generous = HouseRule { slack: "10%".parse().unwrap(), bow: "3".parse().unwrap() };
+    assert!(generous.paper(&parcel) == Some(58) && generous.ribbon(&parcel) == Some(13));
+
+    assert!("5".parse::<Slack>().is_err() && "x%".parse::<Slack>().is_err());
+    assert!("big".parse::<Bow>().is_err());
+}
@@ -0,0 +1,164 @@
+//! Shapes of present and their measurements for wrapping
+
+use crate::Parcel;
+use std::{cmp::min, f64::consts::PI, fmt, str::FromStr};
+
+/// The shape of a present, with lengths in feet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// A box, written "LxWxH"
+    Cuboid(Parcel),
+    /// A box with equal sides, written "cube S"
+    Cube(u32),
+    /// A tube, written "cylinder RxH" with the radius first
+    Cylinder { radius: u32, height: u32 }
+}
+
+impl Shape {
+    /// Returns the area of the whole surface in square feet
+    pub fn surface(&self) -> Option<u64> {
+        match self {
+            Shape::Cuboid(p) => {
+                let (s1, s2, s3) = faces(p)?;
+
+                s1.checked_add(s2)?.checked_add(s3)?.checked_mul(2)
+            }
+            Shape::Cube(s) => Shape::Cuboid(cube(*s)).surface(),
+            Shape::Cylinder { radius, height } => {
+                let (r, h) = (*radius as f64, *height as f64);
+
+                round_up(2.0 * PI * r * (r + h))
+            }
+        }
+    }
+
+    /// Returns the area of the smallest flat face in square feet
+    pub fn smallest_face(&self) -> Option<u64> {
+        match self {
+            Shape::Cuboid(p) => {
+                let (s1, s2, s3) = faces(p)?;
+
+                Some(min(s1, min(s2, s3)))
+            }
+            Shape::Cube(s) => Shape::Cuboid(cube(*s)).smallest_face(),
+            Shape::Cylinder { radius, .. } => round_up(PI * (*radius as f64).powi(2))
+        }
+    }
+
+    /// Returns the shortest distance all the way round in feet
+    pub fn smallest_perimeter(&self) -> Option<u64> {
+        match self {
+            Shape::Cuboid(p) => {
+                let (l, w, h) = (p.l as u64, p.w as u64, p.h as u64);
+
+                Some(2 * min(l + w, min(w + h, h + l)))
+            }
+            Shape::Cube(s) => Shape::Cuboid(cube(*s)).smallest_perimeter(),
+            Shape::Cylinder { radius, height } => {
+                let (r, h) = (*radius as f64, *height as f64);
+
+                // Round the middle, or end to end over both flat faces
+                round_up((2.0 * PI * r).min(4.0 * r + 2.0 * h))
+            }
+        }
+    }
+
+    /// Returns the volume in cubic feet
+    pub fn volume(&self) -> Option<u64> {
+        match self {
+            Shape::Cuboid(p) => (p.l as u64).checked_mul(p.w as u64)?.checked_mul(p.h as u64),
+            Shape::Cube(s) => Shape::Cuboid(cube(*s)).volume(),
+            Shape::Cylinder { radius, height } => round_up(PI * (*radius as f64).powi(2) * *height as f64)
+        }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Cuboid(p) => write!(f, "{}x{}x{}", p.l, p.w, p.h),
+            Shape::Cube(s) => write!(f, "cube {}", s),
+            Shape::Cylinder { radius, height } => write!(f, "cylinder {}x{}", radius, height)
+        }
+    }
+}
+
+impl FromStr for Shape {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut terms = s.split_whitespace();
+
+        match (terms.next(), terms.next(), terms.next()) {
+            (Some(dims), None, None) => Ok(Shape::Cuboid(dims.parse()?)),
+            (Some("cube"), Some(side), None) => side.parse().map(Shape::Cube).map_err(|_| "expected cube S".to_string()),
+            (Some("cylinder"), Some(dims), None) => {
+                let dims: Vec<u32> = dims.split('x').map(|d| d.parse::<u32>()).collect::<Result<_, _>>()
+                    .map_err(|_| "expected cylinder RxH".to_string())?;
+
+                match dims[..] {
+                    [radius, height] => Ok(Shape::Cylinder { radius, height }),
+                    _ => Err("expected cylinder RxH".to_string())
+                }
+            }
+            _ => Err("expected LxWxH, cube S or cylinder RxH".to_string())
+        }
+    }
+}
+
+impl From<Parcel> for Shape {
+    fn from(parcel: Parcel) -> Self {
+        Shape::Cuboid(parcel)
+    }
+}
+
+fn cube(side: u32) -> Parcel {
+    Parcel { l: side, w: side, h: side }
+}
+
+/// Returns the areas of the three different faces of a box
+fn faces(p: &Parcel) -> Option<(u64, u64, u64)> {
+    let (l, w, h) = (p.l as u64, p.w as u64, p.h as u64);
+
+    Some((l.checked_mul(w)?, w.checked_mul(h)?, h.checked_mul(l)?))
+}
+
+/// Rounds a measurement of a curved shape up to a whole number, or None if
+/// it is too large
+fn round_up(value: f64) -> Option<u64> {
+    let value = value.ceil();
+
+    if value < u64::MAX as f64 {
+        Some(value as u64)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_shapes() {
+    let cuboid: Shape = "2x3x4".parse().unwrap();
+    assert!(cuboid == Shape::Cuboid(Parcel { l: 2, w: 3, h: 4 }));
+    assert!(cuboid.surface() == Some(52) && cuboid.smallest_face() == Some(6));
+    assert!(cuboid.smallest_perimeter() == Some(10) && cuboid.volume() == Some(24));
+
+    let cube: Shape = "cube 3".parse().unwrap();
+    assert!(cube == Shape::Cube(3) && cube.to_string() == "cube 3");
+    assert!(cube.surface() == Some(54) && cube.smallest_face() == Some(9));
+    assert!(cube.smallest_perimeter() == Some(12) && cube.volume() == Some(27));
+
+    // 2πr(r + h) = 87.96, πr² = 12.57, min(2πr, 4r + 2h) = 12.57, πr²h = 62.83
+    let cylinder: Shape = "cylinder 2x5".parse().unwrap();
+    assert!(cylinder == Shape::Cylinder { radius: 2, height: 5 } && cylinder.to_string() == "cylinder 2x5");
+    assert!(cylinder.surface() == Some(88) && cylinder.smallest_face() == Some(13));
+    assert!(cylinder.smallest_perimeter() == Some(13) && cylinder.volume() == Some(63));
+
+    // A flat tin is wrapped end to end: 4r + 2h = 42 < 2πr = 62.83
+    assert!(Shape::Cylinder { radius: 10, height: 1 }.smallest_perimeter() == Some(42));
+
+    assert!("cube".parse::<Shape>().is_err());
+    assert!("cylinder 2x3x4".parse::<Shape>().is_err());
+    assert!("sphere 2".parse::<Shape>().is_err());
+    assert!(Shape::Cuboid(Parcel { l: u32::MAX, w: u32::MAX, h: u32::MAX }).volume().is_none());
+    assert!(Shape::Cylinder { radius: u32::MAX, height: u32::MAX }.volume().is_none());
+}
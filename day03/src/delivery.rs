@@ -0,0 +1,238 @@
+//! Deliveries by any number of agents sharing out the directions
+
+use grid_lib::{Coord, Dir, SparseGrid};
+use std::collections::HashMap;
+
+/// How the directions are shared out between the agents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dispatch {
+    /// A number of agents take turns to follow one direction each
+    RoundRobin(usize),
+    /// Each line of directions is followed by its own agent
+    Streams,
+    /// Agents take turns to follow runs of directions, each as long as the
+    /// agent's weight
+    Weighted(Vec<usize>)
+}
+
+impl Dispatch {
+    /// Parses "round-robin", "streams" or "weighted:W1,W2,...". Round robin
+    /// dispatch is to a number of agents
+    pub fn parse(s: &str, agents: usize) -> Result<Self, String> {
+        let dispatch = match s {
+            "round-robin" => Dispatch::RoundRobin(agents),
+            "streams" => Dispatch::Streams,
+            _ => {
+                let weights = s.strip_prefix("weighted:")
+                    .ok_or_else(|| "expected round-robin, streams or weighted:W1,W2,...".to_string())?;
+
+                Dispatch::Weighted(weights.split(',')
+                    .map(|w| w.trim().parse::<usize>().map_err(|_| format!("invalid weight {}", w)))
+                    .collect::<Result<_, _>>()?)
+            }
+        };
+
+        match &dispatch {
+            Dispatch::RoundRobin(0) => Err("at least one agent is needed".to_string()),
+            Dispatch::Weighted(w) if w.iter().all(|w| *w == 0) => Err("at least one weight must not be zero".to_string()),
+            _ => Ok(dispatch)
+        }
+    }
+}
+
+/// One agent's progress
+#[derive(Debug, Clone, Default)]
+struct Agent {
+    pos: Coord,
+    moves: usize,
+    visits: SparseGrid<u32>
+}
+
+impl Agent {
+    fn start() -> Self {
+        let mut agent = Self::default();
+        agent.visits.set(Coord::default(), 1);
+
+        agent
+    }
+
+    fn step(&mut self, dir: Dir) {
+        self.pos += dir.offset();
+        self.moves += 1;
+        *self.visits.get_or_default(self.pos) += 1;
+    }
+}
+
+/// Statistics for one agent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentStats {
+    /// Number of directions followed
+    pub moves: usize,
+    /// Number of different houses visited, including the start
+    pub houses: usize,
+    /// Number of houses no other agent visited
+    pub own_houses: usize,
+    /// Where the agent finished
+    pub end: Coord
+}
+
+/// Statistics for all of the agents together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedStats {
+    /// Number of different houses visited
+    pub houses: usize,
+    /// Number of houses visited by more than one agent
+    pub shared: usize,
+    /// Number of houses visited by every agent
+    pub by_all: usize
+}
+
+/// The result of a delivery. Every agent starts by delivering to the house at
+/// the origin
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    agents: Vec<Agent>
+}
+
+impl Delivery {
+    /// Follows the directions, dispatching them to agents. Anything other than
+    /// arrows (and line breaks between streams) is ignored
+    pub fn simulate(directions: &str, dispatch: &Dispatch) -> Self {
+        let dirs = |text: &str| text.chars().filter_map(Dir::from_arrow).collect::<Vec<_>>();
+
+        let agents = match dispatch {
+            Dispatch::RoundRobin(agents) => {
+                let mut agents = vec![Agent::start(); *agents];
+
+                for (turn, dir) in dirs(directions).into_iter().enumerate() {
+                    let n = agents.len();
+                    agents[turn % n].step(dir);
+                }
+
+                agents
+            }
+            Dispatch::Streams => {
+                directions.lines().filter(|l| !l.trim().is_empty()).map(|line| {
+                    let mut agent = Agent::start();
+                    dirs(line).into_iter().for_each(|dir| agent.step(dir));
+
+                    agent
+                }).collect()
+            }
+            Dispatch::Weighted(weights) => {
+                let mut agents = vec![Agent::start(); weights.len()];
+
+                // The sequence of agents in one full round
+                let round: Vec<usize> = weights.iter().enumerate()
+                    .flat_map(|(agent, w)| std::iter::repeat_n(agent, *w))
+                    .collect();
+
+                for (turn, dir) in dirs(directions).into_iter().enumerate() {
+                    agents[round[turn % round.len()]].step(dir);
+                }
+
+                agents
+            }
+        };
+
+        Self { agents }
+    }
+
+    /// Returns the number of agents
+    pub fn agents(&self) -> usize {
+        self.agents.len()
+    }
+
+    /// Returns the total number of visits to each house
+    pub fn visits(&self) -> SparseGrid<u32> {
+        let mut visits = SparseGrid::new();
+
+        for agent in &self.agents {
+            for (c, n) in agent.visits.iter() {
+                *visits.get_or_default(*c) += n;
+            }
+        }
+
+        visits
+    }
+
+    /// Returns the number of different houses visited
+    pub fn houses(&self) -> usize {
+        match &self.agents[..] {
+            [agent] => agent.visits.len(),
+            _ => self.agent_counts().len()
+        }
+    }
+
+    /// Returns the statistics for each agent
+    pub fn agent_stats(&self) -> Vec<AgentStats> {
+        let counts = self.agent_counts();
+
+        self.agents.iter().map(|agent| AgentStats {
+            moves: agent.moves,
+            houses: agent.visits.len(),
+            own_houses: agent.visits.iter().filter(|(c, _)| counts[c] == 1).count(),
+            end: agent.pos
+        }).collect()
+    }
+
+    /// Returns the statistics for all of the agents together
+    pub fn shared_stats(&self) -> SharedStats {
+        let counts = self.agent_counts();
+
+        SharedStats {
+            houses: counts.len(),
+            shared: counts.values().filter(|n| **n > 1).count(),
+            by_all: counts.values().filter(|n| **n == self.agents.len()).count()
+        }
+    }
+
+    /// Returns the number of agents which visited each house
+    fn agent_counts(&self) -> HashMap<Coord, usize> {
+        let mut counts = HashMap::new();
+
+        for agent in &self.agents {
+            for (c, _) in agent.visits.iter() {
+                *counts.entry(*c).or_default() += 1;
+            }
+        }
+
+        counts
+    }
+}
+
+#[test]
+fn test_dispatch() {
+    assert!(Dispatch::parse("round-robin", 3) == Ok(Dispatch::RoundRobin(3)));
+    assert!(Dispatch::parse("streams", 3) == Ok(Dispatch::Streams));
+    assert!(Dispatch::parse("weighted:2, 1", 3) == Ok(Dispatch::Weighted(vec![2, 1])));
+    assert!(Dispatch::parse("round-robin", 0).is_err());
+    assert!(Dispatch::parse("weighted:0,0", 1).is_err());
+    assert!(Dispatch::parse("weighted:x", 1).is_err());
+    assert!(Dispatch::parse("random", 1).is_err());
+}
+
+#[test]
+fn test_delivery() {
+    // Santa goes up and down, Robo-Santa right and left
+    let delivery = Delivery::simulate("^>v<^>", &Dispatch::RoundRobin(2));
+    assert!(delivery.houses() == 3 && delivery.visits().get(Coord::default()) == Some(&4));
+
+    let stats = delivery.agent_stats();
+    assert!(stats[0] == AgentStats { moves: 3, houses: 2, own_houses: 1, end: Dir::Up.offset() });
+    assert!(stats[1] == AgentStats { moves: 3, houses: 2, own_houses: 1, end: Dir::Right.offset() });
+    assert!(delivery.shared_stats() == SharedStats { houses: 3, shared: 1, by_all: 1 });
+
+    // The same moves as separate streams, and weighted so the first agent
+    // takes two directions for each one the second takes
+    let streams = Delivery::simulate("^v^\n><>\n", &Dispatch::Streams);
+    assert!(streams.agents() == 2 && streams.agent_stats() == stats);
+
+    let weighted = Delivery::simulate("^v>^v<^v", &Dispatch::Weighted(vec![2, 1]));
+    assert!(weighted.agent_stats().iter().map(|s| s.moves).collect::<Vec<_>>() == vec![6, 2]);
+    assert!(weighted.shared_stats() == SharedStats { houses: 3, shared: 1, by_all: 1 });
+
+    // Zero weights never move
+    let idle = Delivery::simulate("^^", &Dispatch::Weighted(vec![0, 1]));
+    assert!(idle.agent_stats()[0].moves == 0 && idle.agent_stats()[1].houses == 3);
+}
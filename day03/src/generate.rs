@@ -0,0 +1,11 @@
+//! Random delivery directions
+
+use aoc_lib::Rng;
+
+/// Generates a string of size arrows
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = rng.string(size.max(1), "^v<>");
+
+    text.push('\n');
+    text
+}
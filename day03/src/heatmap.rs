@@ -0,0 +1,100 @@
+//! Heatmaps of the number of visits to each house, cropped to the houses
+//! visited
+
+use grid_lib::{Coord, SparseGrid};
+use std::{error::Error, fs, path::Path};
+
+/// Characters for increasing numbers of visits, starting with none
+const RAMP: &[u8] = b" .:-=+*#%@";
+
+/// Returns the number of houses visited at least a number of times
+pub fn visited_at_least(visits: &SparseGrid<u32>, times: u32) -> usize {
+    visits.iter().filter(|(_, n)| **n >= times).count()
+}
+
+/// Returns the house with the most visits and its number of visits. A tie goes
+/// to the first house in reading order
+pub fn most_visited(visits: &SparseGrid<u32>) -> Option<(Coord, u32)> {
+    visits.iter()
+        .map(|(c, n)| (*c, *n))
+        .min_by_key(|(c, n)| (u32::MAX - n, c.y, c.x))
+}
+
+/// Renders the visits as text, with denser characters for more visits
+pub fn ascii(visits: &SparseGrid<u32>) -> String {
+    let max = max_visits(visits);
+
+    match visits.bounds() {
+        Some(bounds) => visits.render_text(&bounds, RAMP[0] as char, |n| {
+            RAMP[shade(*n, max, RAMP.len() as u32 - 1) as usize] as char
+        }),
+        None => String::new()
+    }
+}
+
+/// Renders the visits as a plain PGM image, brighter for more visits
+pub fn pgm(visits: &SparseGrid<u32>) -> String {
+    let max = max_visits(visits);
+
+    match visits.bounds() {
+        Some(bounds) => visits.render_pgm(&bounds, |n| shade(*n, max, 255) as u8),
+        None => "P2\n0 0\n255\n".to_string()
+    }
+}
+
+/// Renders the visits as a PNG image, brighter for more visits
+#[cfg(feature = "png")]
+pub fn png(visits: &SparseGrid<u32>) -> Vec<u8> {
+    let max = max_visits(visits);
+
+    match visits.bounds() {
+        Some(bounds) => visits.render_png(&bounds, |n| shade(*n, max, 255) as u8),
+        None => grid_lib::png::encode_gray(0, 0, &[])
+    }
+}
+
+/// Writes a heatmap to a file, choosing the format from its extension: .txt,
+/// .pgm or (if built with the png feature) .png
+pub fn export(visits: &SparseGrid<u32>, file: &Path) -> Result<(), Box<dyn Error>> {
+    let data = match file.extension().and_then(|e| e.to_str()) {
+        Some("txt") => ascii(visits).into_bytes(),
+        Some("pgm") => pgm(visits).into_bytes(),
+        #[cfg(feature = "png")]
+        Some("png") => png(visits),
+        #[cfg(not(feature = "png"))]
+        Some("png") => return Err("PNG heatmaps need the png feature".into()),
+        _ => return Err(format!("{}: expected a .txt, .pgm or .png heatmap file", file.display()).into())
+    };
+
+    fs::write(file, data).map_err(|e| format!("{}: {}", file.display(), e).into())
+}
+
+fn max_visits(visits: &SparseGrid<u32>) -> u32 {
+    visits.iter().map(|(_, n)| *n).max().unwrap_or(0)
+}
+
+/// Scales a number of visits from 1 to the maximum onto 1 to the top shade,
+/// rounding up so that every visited house shows
+fn shade(n: u32, max: u32, top: u32) -> u32 {
+    ((n as u64 * top as u64).div_ceil(max.max(1) as u64) as u32).clamp(1, top)
+}
+
+#[test]
+fn test_heatmap() {
+    use crate::visit_houses;
+
+    // Up and down five times, then one step right
+    let visits = visit_houses("^v^v^v^v^v>", 1);
+
+    assert!(visited_at_least(&visits, 1) == 3 && visited_at_least(&visits, 5) == 2 && visited_at_least(&visits, 6) == 1);
+    assert!(most_visited(&visits) == Some((Coord::new(0, 0), 6)));
+
+    assert!(ascii(&visits) == "% \n@:\n");
+    assert!(pgm(&visits) == "P2\n2 2\n255\n213 0\n255 43\n");
+
+    assert!(ascii(&SparseGrid::new()).is_empty());
+    assert!(most_visited(&SparseGrid::new()).is_none());
+
+    // Ties go to the first house in reading order
+    assert!(most_visited(&visit_houses("^>", 1)) == Some((Coord::new(0, -1), 1)));
+}
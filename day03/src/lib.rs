@@ -0,0 +1,115 @@
+pub mod delivery;
+pub mod generate;
+pub mod heatmap;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult, Solution, Validation};
+use delivery::{Delivery, Dispatch};
+use grid_lib::{Dir, SparseGrid};
+use std::{error::Error, path::{Path, PathBuf}};
+
+pub const DAY: Day = Day {
+    day: 3,
+    input: Some("input03.txt"),
+    parts: &[
+        Part { part: 1, desc: "Houses visited", solve: part1 },
+        Part { part: 2, desc: "Houses visited with Robo-Santa", solve: part2 },
+    ],
+    params: &[
+        Param { name: "validation", desc: "Handling of other characters: strict or lenient (default)" },
+        Param { name: "agents", desc: "Number of agents taking turns in part 2 (default 2)" },
+        Param { name: "heatmap", desc: "File to write heatmaps of the visits to (.txt, .pgm or .png), with -part1 or -part2 added to the name" },
+        Param { name: "at_least", desc: "Number of visits to count the houses visited at least that many times" },
+        Param { name: "dispatch", desc: "Sharing of directions in part 2: round-robin (default), streams or weighted:W1,W2,..." },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let delivery = Delivery::simulate(directions(input)?, &Dispatch::RoundRobin(1));
+
+    solution(input, 1, &delivery)
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let agents = input.param_or("agents", 2)?;
+    let dispatch = Dispatch::parse(&input.param_or("dispatch", "round-robin".to_string())?, agents)?;
+
+    let delivery = Delivery::simulate(directions(input)?, &dispatch);
+    let agent_houses: Vec<usize> = delivery.agent_stats().iter().map(|s| s.houses).collect();
+
+    Ok(solution(input, 2, &delivery)?
+        .with_detail("agent_houses", agent_houses)
+        .with_detail("shared_houses", delivery.shared_stats().shared))
+}
+
+/// Returns the number of houses visited, writing the part's heatmap if asked
+/// for
+fn solution(input: &Input, part: u8, delivery: &Delivery) -> Result<Solution, Box<dyn Error>> {
+    let visits = delivery.visits();
+
+    if let Some(file) = input.param::<PathBuf>("heatmap")? {
+        heatmap::export(&visits, &part_file(&file, part))?;
+    }
+
+    let most_visits = heatmap::most_visited(&visits).map_or(0, |(_, n)| n);
+    let mut solution = Solution::from(visits.len()).with_detail("most_visits", most_visits);
+
+    if let Some(times) = input.param("at_least")? {
+        solution = solution.with_detail("visited_at_least", heatmap::visited_at_least(&visits, times));
+    }
+
+    Ok(solution)
+}
+
+/// Adds the part number to a file name, so "visits.pgm" becomes
+/// "visits-part1.pgm"
+fn part_file(file: &Path, part: u8) -> PathBuf {
+    let mut name = file.file_stem().unwrap_or_default().to_os_string();
+    name.push(format!("-part{}", part));
+
+    if let Some(ext) = file.extension() {
+        name.push(".");
+        name.push(ext);
+    }
+
+    file.with_file_name(name)
+}
+
+/// Follows the directions with agents taking turns to move, returning the
+/// number of visits to each house
+pub fn visit_houses(directions: &str, agents: usize) -> SparseGrid<u32> {
+    Delivery::simulate(directions, &Dispatch::RoundRobin(agents)).visits()
+}
+
+/// Returns the directions once every other character has been checked. Line
+/// breaks separate streams when each agent has its own directions
+fn directions(input: &Input) -> Result<&str, Box<dyn Error>> {
+    let validation = input.param_or("validation", Validation::Lenient)?;
+    let streams = input.param::<String>("dispatch")?.as_deref() == Some("streams");
+
+    validation.check(input.text(), |b| Dir::from_arrow(b as char).is_some() || (streams && b == b'\n'), "one of ^v<>")?;
+
+    Ok(input.text())
+}
+
+#[test]
+fn test_visit_houses() {
+    assert!(visit_houses("^>v<", 1).len() == 4);
+    assert!(visit_houses("^v^v^v^v^v", 2).len() == 11);
+
+    let mut input = Input::from("^v\r\n");
+    assert!(part2(&input).unwrap().answer == 3usize.into());
+
+    input.set_param("validation", "strict");
+    assert!(part2(&input).is_ok());
+
+    let mut input = Input::from("^v^v^v^v^v>");
+    input.set_param("at_least", "5");
+    assert!(part1(&input).unwrap().detail[1] == ("visited_at_least", 2.into()));
+
+    assert!(part_file(Path::new("maps/visits.pgm"), 2) == Path::new("maps/visits-part2.pgm"));
+    assert!(part_file(Path::new("visits"), 1) == Path::new("visits-part1"));
+
+    let input = Input::from("^ v\n^-\n");
+    assert!(part1(&input).unwrap_err().to_string() == "1 invalid bytes, expected one of ^v<>: '-' at 6");
+}
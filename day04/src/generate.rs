@@ -0,0 +1,11 @@
+//! Random secret keys
+
+use aoc_lib::Rng;
+
+/// Generates a secret key of size lower case letters
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = rng.string(size.max(1), "abcdefghijklmnopqrstuvwxyz");
+
+    text.push('\n');
+    text
+}
@@ -0,0 +1,106 @@
+pub mod generate;
+pub mod pow;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult};
+use pow::{Search, Target};
+use md5::Digest;
+
+pub type CheckFn = fn(Digest) -> bool;
+
+pub const DAY: Day = Day {
+    day: 4,
+    input: Some("input04.txt"),
+    parts: &[
+        Part { part: 1, desc: "Number for 5 zeros", solve: part1 },
+        Part { part: 2, desc: "Number for 6 zeros", solve: part2 },
+    ],
+    params: &[
+        Param { name: "key", desc: "Secret key" },
+        Param { name: "difficulty", desc: "Target for part 2: zero hex digits needed, bits:N for zero bits or prefix:HEX (default 6)" },
+        Param { name: "start", desc: "First number to try (default 0)" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    search(input, Target::zero_digits(5)?)
+}
+
+/// Searches for six zero hex digits, or the target given by the difficulty
+/// param. Part 1 always searches for five so the two parts stay distinct
+pub fn part2(input: &Input) -> PartResult {
+    let target = match input.param::<Target>("difficulty")? {
+        Some(target) => target,
+        None => Target::zero_digits(6)?
+    };
+
+    search(input, target)
+}
+
+fn search(input: &Input, target: Target) -> PartResult {
+    let key: String = input.param_or_input("key")?;
+
+    let nonce = Search::new(&key, target)
+        .start(input.param_or("start", 0)?)
+        .find_threaded(threads())
+        .ok_or("No number found")?;
+
+    Ok(nonce.into())
+}
+
+fn threads() -> usize {
+    num_cpus::get()
+}
+
+pub fn calc_hash_number(key: &str, check: CheckFn) -> u32 {
+    for n in 0.. {
+        let digest = md5::compute(format!("{}{}", key, n));
+        if check(digest) {
+            return n;
+        }
+    }
+
+    0
+}
+
+pub fn check5(digest: Digest) -> bool{
+    digest[0] == 0 && digest[1] == 0 && digest[2] & 0xf0 == 0
+}
+
+pub fn check6(digest: Digest) -> bool{
+    digest[0] == 0 && digest[1] == 0 && digest[2] == 0
+}
+
+/// Returns the first number below u32::MAX passing the check, if there is one
+pub fn calc_hash_number_threaded(key: &str, check: fn(Digest) -> bool) -> Option<u32> {
+    let found = pow::first_in_chunks(0..u32::MAX as u64, threads(), pow::CHUNK, |nonces| {
+        nonces.into_iter().find(|n| check(md5::compute(format!("{}{}", key, n))))
+    });
+
+    found.map(|n| n as u32)
+}
+
+#[test]
+fn test_md5() {
+    let digest = md5::compute("abcdef609043");
+    assert!(digest[0] == 0 && digest[1] == 0 && digest[2] & 0xf0 == 0);
+}
+
+#[test]
+fn test_calc_hash_number5() {
+    assert!(calc_hash_number("abcdef", check5) == 609043);
+    assert!(calc_hash_number("pqrstuv", check5) == 1048970);
+}
+
+#[test]
+fn test_difficulty() {
+    // The difficulty only changes part 2
+    let mut input = Input::from("abcdef");
+    input.set_param("start", "609043");
+    input.set_param("difficulty", "2");
+
+    let two = Search::new("abcdef", Target::zero_digits(2).unwrap()).start(609043).find().unwrap();
+
+    assert!(part1(&input).unwrap().answer == 609043u64.into());
+    assert!(part2(&input).unwrap().answer == two.into());
+}
@@ -0,0 +1,254 @@
+//! Proof of work searches for MD5 digests starting with a target pattern
+
+use md5::{Context, Digest};
+use std::{ops::Range, str::FromStr, sync::atomic::{AtomicU64, Ordering}, thread};
+
+/// Number of nonces a thread takes at a time
+pub const CHUNK: u64 = 4096;
+
+/// The leading bits an MD5 digest must have, held as a mask and the value the
+/// masked bytes must equal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    mask: Vec<u8>,
+    value: Vec<u8>
+}
+
+impl Target {
+    /// Digests starting with a number of zero bits
+    pub fn zero_bits(bits: u32) -> Result<Self, String> {
+        if bits > 128 {
+            return Err(format!("a digest has only 128 bits, not {}", bits))
+        }
+
+        let mut mask = vec![0xff; bits as usize / 8];
+
+        if !bits.is_multiple_of(8) {
+            mask.push(0xff << (8 - bits % 8));
+        }
+
+        let value = vec![0; mask.len()];
+
+        Ok(Self { mask, value })
+    }
+
+    /// Digests starting with a number of zero hex digits
+    pub fn zero_digits(digits: u32) -> Result<Self, String> {
+        Self::zero_bits(digits.saturating_mul(4))
+    }
+
+    /// Digests starting with a string of hex digits
+    pub fn hex_prefix(prefix: &str) -> Result<Self, String> {
+        if prefix.len() > 32 {
+            return Err(format!("a digest has only 32 hex digits, not {}", prefix.len()))
+        }
+
+        let mut target = Self { mask: Vec::new(), value: Vec::new() };
+
+        for (i, c) in prefix.chars().enumerate() {
+            let nibble = c.to_digit(16).ok_or_else(|| format!("{} is not a hex digit", c))? as u8;
+
+            if i % 2 == 0 {
+                target.mask.push(0xf0);
+                target.value.push(nibble << 4);
+            } else {
+                *target.mask.last_mut().unwrap() |= 0x0f;
+                *target.value.last_mut().unwrap() |= nibble;
+            }
+        }
+
+        Ok(target)
+    }
+
+    /// Returns true if a digest starts with the target pattern
+    pub fn matches(&self, digest: &Digest) -> bool {
+        self.mask.iter().zip(&self.value).enumerate().all(|(i, (m, v))| digest[i] & m == *v)
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    /// Parses a number of zero hex digits, "bits:N" for a number of zero bits or
+    /// "prefix:HEX" for a hex prefix
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(bits) = s.strip_prefix("bits:") {
+            Self::zero_bits(bits.parse().map_err(|_| format!("invalid number of bits {}", bits))?)
+        } else if let Some(prefix) = s.strip_prefix("prefix:") {
+            Self::hex_prefix(prefix)
+        } else {
+            Self::zero_digits(s.parse().map_err(|_| "expected a number of zero digits, bits:N or prefix:HEX".to_string())?)
+        }
+    }
+}
+
+/// A search for the lowest nonce which, appended to a key in decimal, gives a
+/// digest matching a target
+#[derive(Debug, Clone)]
+pub struct Search<'a> {
+    key: &'a str,
+    target: Target,
+    nonces: Range<u64>
+}
+
+impl<'a> Search<'a> {
+    pub fn new(key: &'a str, target: Target) -> Self {
+        Self {
+            key,
+            target,
+            nonces: 0..u64::MAX
+        }
+    }
+
+    /// Sets the first nonce to try (default 0)
+    pub fn start(mut self, nonce: u64) -> Self {
+        self.nonces.start = nonce;
+        self
+    }
+
+    /// Sets the range of nonces to try (default all)
+    pub fn range(mut self, nonces: Range<u64>) -> Self {
+        self.nonces = nonces;
+        self
+    }
+
+    /// Returns the first matching nonce in the range, if there is one
+    pub fn find(&self) -> Option<u64> {
+        self.find_in(self.nonces.clone())
+    }
+
+    /// Returns the first matching nonce in the range, if there is one, using a
+    /// number of threads
+    pub fn find_threaded(&self, threads: usize) -> Option<u64> {
+        first_in_chunks(self.nonces.clone(), threads, CHUNK, |nonces| self.find_in(nonces))
+    }
+
+    /// Returns the first matching nonce in part of the range
+    fn find_in(&self, nonces: Range<u64>) -> Option<u64> {
+        // Hash the key once and carry on from there for each nonce
+        let mut keyed = Context::new();
+        keyed.consume(self.key);
+
+        let mut digits = [0; 20];
+
+        nonces.into_iter().find(|&nonce| {
+            let mut context = keyed.clone();
+            context.consume(decimal(nonce, &mut digits));
+
+            self.target.matches(&context.compute())
+        })
+    }
+}
+
+/// Returns the lowest match in a range, using threads which each take the next
+/// chunk of the range and search it. A match is published with an atomic
+/// minimum, and as chunks are handed out in order a thread stops as soon as its
+/// next chunk starts past the lowest match so far
+pub fn first_in_chunks<F>(nonces: Range<u64>, threads: usize, chunk: u64, search: F) -> Option<u64>
+where
+    F: Fn(Range<u64>) -> Option<u64> + Sync
+{
+    let next = AtomicU64::new(nonces.start);
+    let best = AtomicU64::new(u64::MAX);
+
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| loop {
+                // Saturate rather than wrap round to the start of the range
+                let start = next.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| Some(n.saturating_add(chunk))).unwrap();
+                let end = start.saturating_add(chunk).min(nonces.end).min(best.load(Ordering::Relaxed));
+
+                if start >= end {
+                    break
+                }
+
+                if let Some(found) = search(start..end) {
+                    best.fetch_min(found, Ordering::Relaxed);
+                    break
+                }
+            });
+        }
+    });
+
+    // The end of the range is exclusive, so the largest nonce is never a match
+    Some(best.into_inner()).filter(|n| *n != u64::MAX)
+}
+
+/// Writes a number in decimal into a buffer, returning the digits
+fn decimal(mut n: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut pos = buf.len();
+
+    loop {
+        pos -= 1;
+        buf[pos] = b'0' + (n % 10) as u8;
+        n /= 10;
+
+        if n == 0 {
+            return &buf[pos..]
+        }
+    }
+}
+
+#[test]
+fn test_targets() {
+    let digest = md5::compute("abcdef609043");
+
+    assert!(Target::zero_digits(5).unwrap().matches(&digest));
+    assert!(!Target::zero_digits(6).unwrap().matches(&digest));
+    assert!(Target::zero_bits(21).unwrap().matches(&digest) && !Target::zero_bits(24).unwrap().matches(&digest));
+    assert!(Target::hex_prefix("000001dbbfa").unwrap().matches(&digest));
+    assert!(!Target::hex_prefix("000001dbbfb").unwrap().matches(&digest));
+
+    assert!("5".parse::<Target>() == Target::zero_digits(5));
+    assert!("bits:20".parse::<Target>() == Target::zero_digits(5));
+    assert!("prefix:00000".parse::<Target>() == Target::zero_digits(5));
+    assert!(Target::zero_bits(0).unwrap().matches(&digest));
+
+    assert!("prefix:0g".parse::<Target>().is_err());
+    assert!("bits:129".parse::<Target>().is_err());
+    assert!("five".parse::<Target>().is_err());
+
+    let mut buf = [0; 20];
+    assert!(decimal(0, &mut buf) == b"0" && decimal(u64::MAX, &mut buf) == b"18446744073709551615");
+}
+
+#[test]
+fn test_search() {
+    let five = Target::zero_digits(5).unwrap();
+
+    assert!(Search::new("abcdef", five.clone()).find() == Some(609043));
+    assert!(Search::new("abcdef", five.clone()).start(609043).find() == Some(609043));
+    assert!(Search::new("abcdef", five.clone()).range(0..609043).find().is_none());
+
+    // Two zero digits are quick to find, so search on past the first
+    let two = Target::zero_digits(2).unwrap();
+    let first = Search::new("abcdef", two.clone()).find().unwrap();
+    let second = Search::new("abcdef", two.clone()).start(first + 1).find().unwrap();
+
+    assert!(second > first && two.matches(&md5::compute(format!("abcdef{}", second))));
+}
+
+#[test]
+fn test_threaded_search() {
+    // Threaded searches always agree with the sequential search, including
+    // with more threads than chunks before the answer and with tiny chunks so
+    // several threads find matches at once
+    for key in ["abcdef", "pqrstuv", "xyz", ""] {
+        for target in [Target::zero_digits(2).unwrap(), Target::zero_bits(10).unwrap(), Target::hex_prefix("abc").unwrap()] {
+            let search = Search::new(key, target);
+            let expected = search.find();
+
+            for threads in [1, 3, 8] {
+                assert!(search.find_threaded(threads) == expected);
+
+                let small = first_in_chunks(0..u64::MAX, threads, 7, |nonces| search.find_in(nonces));
+                assert!(small == expected);
+            }
+        }
+    }
+
+    let search = Search::new("abcdef", Target::zero_digits(5).unwrap());
+    assert!(search.clone().range(0..609043).find_threaded(4).is_none());
+    assert!(search.clone().range(u64::MAX - 10..u64::MAX).find_threaded(4).is_none());
+    assert!(search.find_threaded(4) == Some(609043));
+}
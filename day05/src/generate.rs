@@ -0,0 +1,33 @@
+//! Random strings to classify
+
+use aoc_lib::Rng;
+
+/// Generates size lines of 16 lower case letters. Vowels and a few of the
+/// letter pairs the rules look for are more common than by chance
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for _ in 0..size.max(1) {
+        let mut line = String::new();
+
+        while line.len() < 16 {
+            match rng.below(10) {
+                0 => line += *rng.choose(&["ab", "cd", "pq", "xy"]),
+                1 | 2 => line += &rng.string(1, "aeiou"),
+                3 => {
+                    // A doubled or repeated letter
+                    let c = rng.string(1, "abcdefghijklmnopqrstuvwxyz");
+                    line += &c;
+                    line += &c;
+                }
+                _ => line += &rng.string(1, "abcdefghijklmnopqrstuvwxyz")
+            }
+        }
+
+        line.truncate(16);
+        text += &line;
+        text.push('\n');
+    }
+
+    text
+}
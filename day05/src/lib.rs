@@ -0,0 +1,119 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Part, PartResult};
+
+pub const DAY: Day = Day {
+    day: 5,
+    input: Some("input05.txt"),
+    parts: &[
+        Part { part: 1, desc: "Nice lines", solve: part1 },
+        Part { part: 2, desc: "Nice lines with new rules", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let nice = input.lines().iter().filter(|s| analyse_line1(s) == LineStatus::Nice).count();
+
+    Ok(nice.into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let nice = input.lines().iter().filter(|s| analyse_line2(s) == LineStatus::Nice).count();
+
+    Ok(nice.into())
+}
+
+#[derive(PartialEq, Eq)]
+pub enum LineStatus {
+    Naughty,
+    Nice
+}
+
+pub fn analyse_line1(line: &str) -> LineStatus {
+    let mut vowel_count = 0;
+
+    for c in line.chars() {
+        match c {
+            'a' | 'e' | 'i' | 'o' | 'u' => vowel_count += 1,
+            _ => {}
+        }
+    }
+
+    if vowel_count < 3 {
+        return  LineStatus::Naughty
+    }
+
+    let mut double = false;
+
+    for i in 1..line.len() {
+        if line.as_bytes()[i - 1] == line.as_bytes()[i] {
+            double = true;
+            break
+        }
+    }
+
+    if !double {
+        return LineStatus::Naughty
+    }
+
+    for i in 1..line.len() {
+        match (line.as_bytes()[i - 1], line.as_bytes()[i]) {
+            (b'a', b'b') | (b'c', b'd') | (b'p', b'q') | (b'x', b'y') => return LineStatus::Naughty,
+            _ => {}
+        }
+    }
+
+    LineStatus::Nice
+}
+
+pub fn analyse_line2(line: &str) -> LineStatus {
+    let chars: Vec<char> = line.chars().collect();
+
+    let mut found = false;
+
+    for i in 1..chars.len() - 2 {
+        for j in i + 2..chars.len() {
+            if chars[i - 1] == chars[j - 1] && chars[i] == chars[j] {
+                // Found two doubles
+                found = true;
+                break;
+            }
+        }
+    }
+
+    if !found {
+        return LineStatus::Naughty;
+    }
+
+    found = false;
+
+    for i in 0..chars.len() - 2 {
+        if chars[i] == chars[i + 2] {
+            // Found a pair with 1 character gap
+            found = true;
+            break;
+        }
+    }
+
+    if !found {
+        return LineStatus::Naughty;
+    }
+
+    LineStatus::Nice
+}
+
+#[test]
+fn test_analyse_line2() {
+    assert!(analyse_line2("qjhvhtzxzqqjkmpb") == LineStatus::Nice);
+    assert!(analyse_line2("xxyxx") == LineStatus::Nice);
+    assert!(analyse_line2("xxxxaba") == LineStatus::Nice);
+    assert!(analyse_line2("abaxxxx") == LineStatus::Nice);
+    assert!(analyse_line2("tdfvkreormspprer") == LineStatus::Nice);
+    assert!(analyse_line2("xxxaba") == LineStatus::Naughty);
+    assert!(analyse_line2("abaxxx") == LineStatus::Naughty);
+    assert!(analyse_line2("abcxxxcbd") == LineStatus::Naughty);
+    assert!(analyse_line2("uurcxstgmygtbstg") == LineStatus::Naughty);
+    assert!(analyse_line2("ieodomkazucvgmuy") == LineStatus::Naughty);
+}
@@ -0,0 +1,18 @@
+//! Random light instructions
+
+use aoc_lib::Rng;
+
+/// Generates size instructions for rectangles within the 1000 x 1000 grid
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for _ in 0..size.max(1) {
+        let action = rng.choose(&["turn on", "turn off", "toggle"]);
+        let (x1, y1) = (rng.range(0..=999), rng.range(0..=999));
+        let (x2, y2) = (rng.range(x1..=999), rng.range(y1..=999));
+
+        text += &format!("{} {},{} through {},{}\n", action, x1, y1, x2, y2);
+    }
+
+    text
+}
@@ -0,0 +1,111 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult};
+use grid_lib::{Coord, DenseGrid, Rect};
+use regex::Regex;
+use std::cmp::max;
+
+pub const DAY: Day = Day {
+    day: 6,
+    input: Some("input06.txt"),
+    parts: &[
+        Part { part: 1, desc: "Bulbs lit", solve: part1 },
+        Part { part: 2, desc: "Total brightness", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let instructions = parse_instructions(&input.numbered_lines())?;
+
+    // Construct light array
+    let mut board = DenseGrid::new(1000, 1000, false);
+
+    for i in &instructions {
+        board.update_rect(&i.rect, |cell| {
+            *cell = match i.action {
+                Action::TurnOff => false,
+                Action::TurnOn => true,
+                Action::Toggle => !*cell
+            }
+        });
+    }
+
+    Ok(board.count(|&c| c).into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let instructions = parse_instructions(&input.numbered_lines())?;
+
+    // Construct light array
+    let mut board = DenseGrid::new(1000, 1000, 0);
+
+    for i in &instructions {
+        board.update_rect(&i.rect, |cell| {
+            *cell = max(0, *cell + match i.action {
+                Action::TurnOff => -1,
+                Action::TurnOn => 1,
+                Action::Toggle => 2
+            });
+        });
+    }
+
+    let brightness: i32 = board.values().sum();
+
+    Ok(brightness.into())
+}
+
+#[derive(Debug)]
+pub enum Action {
+    TurnOn,
+    TurnOff,
+    Toggle
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub action: Action,
+    pub rect: Rect
+}
+
+pub fn parse_instructions(lines: &[Line]) -> Result<Vec<Instruction>, ParseError> {
+    let re = Regex::new(r"^(.*) (\d+),(\d+) through (\d+),(\d+)$").unwrap();
+
+    lines.iter().map(|l| {
+        let caps = re.captures(l.text)
+            .ok_or_else(|| l.line_error("\"<action> x1,y1 through x2,y2\""))?;
+
+        let action = match caps.get(1).unwrap().as_str() {
+            "turn on" => Action::TurnOn,
+            "turn off" => Action::TurnOff,
+            "toggle" => Action::Toggle,
+            other => return Err(l.error(other, "\"turn on\", \"turn off\" or \"toggle\""))
+        };
+
+        let coord = |n| l.parse::<i32>(caps.get(n).unwrap().as_str(), "a coordinate");
+
+        Ok(Instruction {
+            action,
+            rect: Rect::new(Coord::new(coord(2)?, coord(3)?), Coord::new(coord(4)?, coord(5)?))
+        })
+    }).collect()
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("turn on 0,0 through 9,9\n\nswitch 1,1 through 2,2\n");
+
+    let err = parse_instructions(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 3 && err.column == 1 && err.text == "switch" && err.expected == "\"turn on\", \"turn off\" or \"toggle\"");
+
+    let input = Input::from("toggle 1,x through 5,5\n");
+
+    let err = parse_instructions(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 1 && err.text == "toggle 1,x through 5,5" && err.expected == "\"<action> x1,y1 through x2,y2\"");
+
+    let input = Input::from("toggle 1,99999999999 through 5,5\n");
+
+    let err = parse_instructions(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 10 && err.text == "99999999999" && err.expected == "a coordinate");
+}
@@ -0,0 +1,49 @@
+//! Random circuit netlists
+
+use aoc_lib::Rng;
+
+/// Generates a circuit of about size gates in random order. Each gate only
+/// reads wires defined before it, so the circuit has no loops. Wire b is set
+/// from a signal and wire a is driven by the last gate
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut wires = vec!["b".to_string()];
+    let mut gates = vec![format!("{} -> b", rng.range(0..=65535))];
+
+    for i in 0..size.max(2) - 1 {
+        let out = if i == size.max(2) - 2 { "a".to_string() } else { wire_name(i) };
+
+        let in1 = rng.choose(&wires).clone();
+        let in2 = rng.choose(&wires).clone();
+
+        let gate = match rng.below(7) {
+            0 => format!("{} -> {}", rng.range(0..=65535), out),
+            1 => format!("{} -> {}", in1, out),
+            2 => format!("{} AND {} -> {}", in1, in2, out),
+            3 => format!("{} OR {} -> {}", in1, in2, out),
+            4 => format!("{} LSHIFT {} -> {}", in1, rng.range(1..=15), out),
+            5 => format!("{} RSHIFT {} -> {}", in1, rng.range(1..=15), out),
+            _ => format!("NOT {} -> {}", in1, out)
+        };
+
+        gates.push(gate);
+        wires.push(out);
+    }
+
+    rng.shuffle(&mut gates);
+
+    gates.iter().map(|g| format!("{}\n", g)).collect()
+}
+
+/// Returns a unique wire name of at least two letters
+fn wire_name(i: usize) -> String {
+    let mut name = Vec::new();
+    let mut n = i + 26;
+
+    while n > 0 {
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
@@ -0,0 +1,379 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult};
+use regex::Regex;
+use std::{collections::HashMap, error::Error, fmt};
+
+pub const DAY: Day = Day {
+    day: 7,
+    input: Some("input07.txt"),
+    parts: &[
+        Part { part: 1, desc: "Signal a", solve: part1 },
+        Part { part: 2, desc: "Signal a with b overridden", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    Ok(signal_a(&input.numbered_lines())?.into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let lines = input.numbered_lines();
+
+    let part1sig = signal_a(&lines)?;
+
+    Ok(signal_a_override_b(&lines, part1sig)?.into())
+}
+
+pub fn signal_a(lines: &[Line]) -> Result<u16, CircuitError> {
+    let gates = parse_gates(lines)?;
+
+    let mut signals = get_signals(&gates);
+
+    // Resolve the gates
+    resolve_gates(gates, &mut signals)?;
+
+    // Get signal a
+    driven_value(&signals, "a")
+}
+
+pub fn signal_a_override_b(lines: &[Line], part1sig: u16) -> Result<u16, CircuitError> {
+    let mut gates = parse_gates(lines)?;
+
+    let mut signals = get_signals(&gates);
+
+    // Find and remove signal b initialisation from the gates
+    let elem = gates
+        .iter()
+        .position(|gate| matches!(gate, Gate::Signal(outs, _) if outs == "b"))
+        .ok_or_else(|| CircuitError::NotDriven("b".to_string()))?;
+
+    gates.swap_remove(elem);
+
+    // Set signal b
+    set_signal_value(&mut signals, "b", part1sig);
+
+    // Resolve the gates
+    resolve_gates(gates, &mut signals)?;
+
+    // Get signal a
+    driven_value(&signals, "a")
+}
+
+/// Resolves gates until every one has its output signal set. Fails if a pass
+/// resolves no gates, which happens when a gate reads a wire nothing drives or
+/// when gates form a loop
+pub fn resolve_gates(mut gates: Vec<Gate>, signals: &mut SignalMap) -> Result<(), CircuitError> {
+    while !gates.is_empty() {
+        let mut gate_no = 0;
+        let remaining = gates.len();
+
+        while gate_no < gates.len() {
+            let gate = &gates[gate_no];
+            let mut resolved = false;
+
+            match gate {
+                Gate::Signal(outs, ins) => {
+                    if let Some(value) = get_input_value(signals, ins) {
+                        set_signal_value(signals, outs, value);
+                        resolved = true;
+                    }
+                },
+                Gate::And(outs, ins1, ins2) => {
+                    if let Some(value1) = get_input_value(signals, ins1) {
+                        if let Some(value2) = get_input_value(signals, ins2) {
+                            set_signal_value(signals, outs, value1 & value2);
+                            resolved = true;
+                        }
+                    }
+                },
+                Gate::Or(outs, ins1, ins2) => {
+                    if let Some(value1) = get_input_value(signals, ins1) {
+                        if let Some(value2) = get_input_value(signals, ins2) {
+                            set_signal_value(signals, outs, value1 | value2);
+                            resolved = true;
+                        }
+                    }
+                },
+                Gate::LShift(outs, ins, bits) => {
+                    if let Some(value) = get_input_value(signals, ins) {
+                        set_signal_value(signals, outs, value << bits);
+                        resolved = true;
+                    }
+                },
+                Gate::RShift(outs, ins, bits) => {
+                    if let Some(value) = get_input_value(signals, ins) {
+                        set_signal_value(signals, outs, value >> bits);
+                        resolved = true;
+                    }
+                },
+                Gate::Not(outs, ins) => {
+                    if let Some(value) = get_input_value(signals, ins) {
+                        set_signal_value(signals, outs, !value);
+                        resolved = true;
+                    }
+                }
+            }
+
+            if resolved {
+                gates.swap_remove(gate_no);
+            } else {
+                gate_no += 1;
+            }
+        }
+
+        if gates.len() == remaining {
+            return Err(unresolved(&gates, signals))
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the error for gates which can't be resolved, naming a wire nothing
+/// drives if there is one, otherwise the wires left without signals
+fn unresolved(gates: &[Gate], signals: &SignalMap) -> CircuitError {
+    let mut undriven: Vec<&str> = gates.iter()
+        .flat_map(|gate| gate.inputs())
+        .filter(|name| get_signal_value(signals, name).is_none() && !gates.iter().any(|g| g.output() == *name))
+        .collect();
+
+    if !undriven.is_empty() {
+        undriven.sort_unstable();
+        return CircuitError::NotDriven(undriven[0].to_string())
+    }
+
+    let mut wires: Vec<String> = gates.iter().map(|gate| gate.output().to_string()).collect();
+    wires.sort_unstable();
+
+    CircuitError::Unresolved(wires)
+}
+
+/// An error evaluating a circuit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The circuit description is invalid
+    Parse(ParseError),
+    /// A wire is needed but no gate drives it
+    NotDriven(String),
+    /// Gates which feed each other in a loop, named by their output wires
+    Unresolved(Vec<String>)
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::Parse(e) => write!(f, "{}", e),
+            CircuitError::NotDriven(wire) => write!(f, "wire {} not driven", wire),
+            CircuitError::Unresolved(wires) => write!(f, "wires {} can't be resolved", wires.join(", "))
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+impl From<ParseError> for CircuitError {
+    fn from(e: ParseError) -> Self {
+        CircuitError::Parse(e)
+    }
+}
+
+pub type Out = String;
+
+#[derive(Debug)]
+pub enum In {
+    Signal(u16),
+    Input(String)
+}
+
+#[derive(Debug)]
+pub enum Gate {
+    Signal(Out, In), // out, signal
+    And(Out, In, In), // Out, In1, In2
+    Or(Out, In, In), // Out, In1, In2
+    LShift(Out, In, u16), // Out, In, bits
+    RShift(Out, In, u16), // Out, In, bits
+    Not(Out, In), // Out, In
+}
+
+impl Gate {
+    /// Returns the name of the gate's output wire
+    pub fn output(&self) -> &str {
+        match self {
+            Gate::Signal(outs, _) | Gate::And(outs, _, _) | Gate::Or(outs, _, _) |
+            Gate::LShift(outs, _, _) | Gate::RShift(outs, _, _) | Gate::Not(outs, _) => outs
+        }
+    }
+
+    /// Returns the names of the wires the gate reads
+    pub fn inputs(&self) -> Vec<&str> {
+        let ins = match self {
+            Gate::And(_, ins1, ins2) | Gate::Or(_, ins1, ins2) => vec![ins1, ins2],
+            Gate::Signal(_, ins) | Gate::LShift(_, ins, _) | Gate::RShift(_, ins, _) | Gate::Not(_, ins) => vec![ins]
+        };
+
+        ins.into_iter().filter_map(|ins| match ins {
+            In::Input(name) => Some(name.as_str()),
+            In::Signal(_) => None
+        }).collect()
+    }
+}
+
+pub fn parse_gates(lines: &[Line]) -> Result<Vec<Gate>, ParseError> {
+    let re_sig = Regex::new(r"^([a-z]+|\d+) -> ([a-z]+)$").unwrap();
+    let re_andor = Regex::new(r"^([a-z]+|\d+) (AND|OR) ([a-z]+) -> ([a-z]+)$").unwrap();
+    let re_sh = Regex::new(r"^([a-z]+) ([LR])SHIFT (\d+) -> ([a-z]+)$").unwrap();
+    let re_not = Regex::new(r"^NOT ([a-z]+) -> ([a-z]+)$").unwrap();
+
+    lines.iter().map(|l| {
+        let gate = if let Some(caps) = re_sig.captures(l.text) {
+            Gate::Signal(caps[2].to_string(), parse_in(l, &caps[1])?)
+        } else if let Some(caps) = re_andor.captures(l.text) {
+            let ins1 = parse_in(l, &caps[1])?;
+            let ins2 = parse_in(l, &caps[3])?;
+
+            match &caps[2] {
+                "AND" => Gate::And(caps[4].to_string(), ins1, ins2),
+                _ => Gate::Or(caps[4].to_string(), ins1, ins2)
+            }
+        } else if let Some(caps) = re_sh.captures(l.text) {
+            let bits = l.parse::<u16>(&caps[3], "a shift of 0 to 15 bits")
+                .and_then(|bits| if bits < 16 { Ok(bits) } else { Err(l.error(&caps[3], "a shift of 0 to 15 bits")) })?;
+
+            match &caps[2] {
+                "L" => Gate::LShift(caps[4].to_string(), parse_in(l, &caps[1])?, bits),
+                _ => Gate::RShift(caps[4].to_string(), parse_in(l, &caps[1])?, bits)
+            }
+        } else if let Some(caps) = re_not.captures(l.text) {
+            Gate::Not(caps[2].to_string(), parse_in(l, &caps[1])?)
+        } else {
+            return Err(l.line_error("a gate (\"<in> -> <out>\", \"<in> AND|OR <in> -> <out>\", \"<in> LSHIFT|RSHIFT <n> -> <out>\" or \"NOT <in> -> <out>\")"))
+        };
+
+        Ok(gate)
+    }).collect()
+}
+
+fn parse_in(line: &Line, string: &str) -> Result<In, ParseError> {
+    if string.starts_with(|c: char| c.is_ascii_digit()) {
+        Ok(In::Signal(line.parse(string, "a 16 bit signal")?))
+    } else {
+        Ok(In::Input(string.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Signal {
+    pub value: Option<u16>
+}
+
+pub type SignalMap = HashMap<String, Signal>;
+
+pub fn get_signals(gates: &[Gate]) -> SignalMap {
+    let mut signals = SignalMap::new();
+
+    for gate in gates {
+        match gate {
+            Gate::Signal(outs, ins) => {
+                add_signal(&mut signals, outs);
+                add_in_signal(&mut signals, ins);
+            },
+            Gate::And(outs, ins1, ins2) => {
+                add_signal(&mut signals, outs);
+                add_in_signal(&mut signals, ins1);
+                add_in_signal(&mut signals, ins2);
+            },
+            Gate::Or(outs, ins1, ins2) => {
+                add_signal(&mut signals, outs);
+                add_in_signal(&mut signals, ins1);
+                add_in_signal(&mut signals, ins2);
+            },
+            Gate::LShift(outs, ins, _) => {
+                add_signal(&mut signals, outs);
+                add_in_signal(&mut signals, ins);
+            },
+            Gate::RShift(outs, ins, _) => {
+                add_signal(&mut signals, outs);
+                add_in_signal(&mut signals, ins);
+            },
+            Gate::Not(outs, ins) => {
+                add_signal(&mut signals, outs);
+                add_in_signal(&mut signals, ins);
+            }
+        }
+    }
+
+    signals
+}
+
+fn add_signal(signals: &mut SignalMap, signal: &str) {
+    signals.insert(signal.to_string(), Signal {
+        value: None
+    });
+}
+
+fn add_in_signal(signals: &mut SignalMap, ins: &In)  {
+    if let In::Input(name) = ins {
+        add_signal(signals, name);
+    }
+}
+
+pub fn get_signal_value(signals: &SignalMap, name: &str) -> Option<u16> {
+    signals.get(name).and_then(|signal| signal.value)
+}
+
+/// Returns the signal on a wire of a resolved circuit
+fn driven_value(signals: &SignalMap, name: &str) -> Result<u16, CircuitError> {
+    get_signal_value(signals, name).ok_or_else(|| CircuitError::NotDriven(name.to_string()))
+}
+
+fn set_signal_value(signals: &mut SignalMap, name: &str, value: u16) {
+    let val = signals.get_mut(name).unwrap();
+    val.value = Some(value);
+}
+
+fn get_input_value(signals: &SignalMap, ins: &In) -> Option<u16> {
+    match ins {
+        In::Input(insig) => {
+            get_signal_value(signals, insig)
+        },
+        In::Signal(value) => {
+            Some(*value)
+        }
+    }
+}
+
+#[test]
+fn test_circuit_errors() {
+    let input = Input::from("123 -> x\nx AND y -> a\n");
+    assert!(signal_a(&input.numbered_lines()) == Err(CircuitError::NotDriven("y".to_string())));
+
+    let input = Input::from("123 -> x\nNOT x -> y\n");
+    assert!(signal_a(&input.numbered_lines()).unwrap_err().to_string() == "wire a not driven");
+
+    let input = Input::from("123 -> x\nx -> a\n");
+    assert!(signal_a(&input.numbered_lines()) == Ok(123));
+    assert!(signal_a_override_b(&input.numbered_lines(), 1) == Err(CircuitError::NotDriven("b".to_string())));
+
+    // A loop never resolves
+    let input = Input::from("1 -> b\nb AND c -> a\nNOT a -> c\n");
+    assert!(signal_a(&input.numbered_lines()) == Err(CircuitError::Unresolved(vec!["a".to_string(), "c".to_string()])));
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("123 -> x\n\nx XOR y -> z\n");
+
+    let err = parse_gates(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 3 && err.column == 1 && err.text == "x XOR y -> z" && err.expected.starts_with("a gate"));
+
+    let input = Input::from("x LSHIFT 16 -> y\n70000 -> z\n");
+
+    let err = parse_gates(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 10 && err.text == "16" && err.expected == "a shift of 0 to 15 bits");
+
+    let err = parse_gates(&input.numbered_lines()[1..]).unwrap_err();
+    assert!(err.line == 2 && err.column == 1 && err.text == "70000" && err.expected == "a 16 bit signal");
+}
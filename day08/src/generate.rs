@@ -0,0 +1,26 @@
+//! Random string literals
+
+use aoc_lib::Rng;
+
+/// Generates size quoted string literals containing escaped backslashes,
+/// quotes and hex characters
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for _ in 0..size.max(1) {
+        text.push('"');
+
+        for _ in 0..rng.range(0..=30) {
+            match rng.below(10) {
+                0 => text += "\\\\",
+                1 => text += "\\\"",
+                2 => text += &format!("\\x{}", rng.string(2, "0123456789abcdef")),
+                _ => text += &rng.string(1, "abcdefghijklmnopqrstuvwxyz")
+            }
+        }
+
+        text += "\"\n";
+    }
+
+    text
+}
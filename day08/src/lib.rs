@@ -0,0 +1,143 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult};
+
+pub const DAY: Day = Day {
+    day: 8,
+    input: Some("input08.txt"),
+    parts: &[
+        Part { part: 1, desc: "Decoded length difference", solve: part1 },
+        Part { part: 2, desc: "Encoded length difference", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let strings = parse_strings(&input.numbered_lines())?;
+    let sum: usize = strings.iter().map(|s| decode_diff(s)).sum();
+
+    Ok(sum.into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let strings = parse_strings(&input.numbered_lines())?;
+    let sum: usize = strings.iter().map(|s| encode_diff(s)).sum();
+
+    Ok(sum.into())
+}
+
+/// Checks that each line is a double quoted string literal containing only
+/// \\, \" and \x<hex><hex> escapes
+pub fn parse_strings<'a>(lines: &[Line<'a>]) -> Result<Vec<&'a str>, ParseError> {
+    lines.iter().map(|l| {
+        let body = l.text.strip_prefix('"').and_then(|t| t.strip_suffix('"'))
+            .ok_or_else(|| l.line_error("a double quoted string"))?;
+
+        let mut rest = body;
+
+        while let Some(pos) = rest.find(['\\', '"']) {
+            let escape = &rest[pos..];
+
+            let len = match escape.as_bytes() {
+                [b'"', ..] => return Err(l.error(&escape[..1], "an escaped \\\"")),
+                [b'\\', b'\\', ..] | [b'\\', b'"', ..] => 2,
+                [b'\\', b'x', ..] if escape.get(2..4).is_some_and(|h| h.bytes().all(|b| b.is_ascii_hexdigit())) => 4,
+                [b'\\', b'x', ..] => {
+                    let end = escape.char_indices().nth(4).map_or(escape.len(), |(i, _)| i);
+                    return Err(l.error(&escape[..end], "\\x and two hex digits"))
+                },
+                _ => {
+                    let end = escape.char_indices().nth(2).map_or(escape.len(), |(i, _)| i);
+                    return Err(l.error(&escape[..end], "\\\\, \\\" or \\x<hex><hex>"))
+                }
+            };
+
+            rest = &escape[len..];
+        }
+
+        Ok(l.text)
+    }).collect()
+}
+
+pub fn decode_diff(string: &str) -> usize {
+    string.chars().count() - count_decode_chars(string)
+}
+
+/// Counts the characters a string literal checked by parse_strings decodes to
+pub fn count_decode_chars(string: &str) -> usize {
+    let mut c = 1;
+    let mut count = 0;
+
+    let chars: Vec<char> = string.chars().collect();
+
+    while c < chars.len() - 1 {
+        c += match chars[c] {
+            '\\' if chars[c + 1] == 'x' => 4,
+            '\\' => 2,
+            _ => 1
+        };
+
+        count += 1
+    }
+
+    count
+}
+
+pub fn encode_diff(string: &str) -> usize {
+    count_encode_chars(string) - string.chars().count()
+}
+
+pub fn count_encode_chars(string: &str) -> usize {
+    let mut count = 0;
+
+    for c in string.chars() {
+        count += match c {
+            '\\' | '"' => 2,
+            _ => 1
+        }
+    }
+
+    2 + count
+}
+
+#[test]
+fn test_count_decode_chars() {
+    assert!(count_decode_chars("\"\"") == 0);
+    assert!(count_decode_chars("\"abc\"") == 3);
+    assert!(count_decode_chars("\"aaa\\\"aaa\"") == 7);
+    assert!(count_decode_chars("\"\\x27\"") == 1);
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("\"abc\\q\"\n");
+
+    let err = parse_strings(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 5 && err.text == "\\q" && err.expected == "\\\\, \\\" or \\x<hex><hex>");
+
+    let input = Input::from("\"\"\n\n\"é\\x4g\"\n");
+
+    let err = parse_strings(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 3 && err.column == 3 && err.text == "\\x4g" && err.expected == "\\x and two hex digits");
+
+    let input = Input::from("\"a\"b\"\n");
+
+    let err = parse_strings(&input.numbered_lines()).unwrap_err();
+    assert!(err.column == 3 && err.text == "\"" && err.expected == "an escaped \\\"");
+
+    let input = Input::from("\"abc\\\"\n");
+
+    let err = parse_strings(&input.numbered_lines()).unwrap_err();
+    assert!(err.column == 5 && err.text == "\\");
+
+    for text in &["abc", "\""] {
+        let input = Input::from(*text);
+
+        let err = parse_strings(&input.numbered_lines()).unwrap_err();
+        assert!(err.column == 1 && err.text == *text && err.expected == "a double quoted string");
+    }
+
+    let input = Input::from("\"é\\x27\"\n");
+    assert!(part1(&input).unwrap().answer == 5usize.into() && part2(&input).unwrap().answer == 5usize.into());
+}
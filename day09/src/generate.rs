@@ -0,0 +1,27 @@
+//! Random distance tables
+
+use aoc_lib::Rng;
+
+const CITIES: [&str; 12] = ["Faerun", "Norrath", "Tristram", "AlphaCentauri", "Arbre", "Snowdin",
+    "Tambi", "Straylight", "London", "Dublin", "Belfast", "Gotham"];
+
+/// Generates the distances between every pair of size cities
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let cities = names(size.max(2));
+    let mut text = String::new();
+
+    for (i, from) in cities.iter().enumerate() {
+        for to in &cities[i + 1..] {
+            text += &format!("{} to {} = {}\n", from, to, rng.range(1..=150));
+        }
+    }
+
+    text
+}
+
+fn names(count: usize) -> Vec<String> {
+    (0..count).map(|i| match i / CITIES.len() {
+        0 => CITIES[i].to_string(),
+        n => format!("{}{}", CITIES[i % CITIES.len()], n + 1)
+    }).collect()
+}
@@ -0,0 +1,109 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult, Solution, trace};
+use regex::Regex;
+use std::collections::HashMap;
+use tour_lib::{Goal, Graph, Tour, TourSearch};
+
+pub const DAY: Day = Day {
+    day: 9,
+    input: Some("input09.txt"),
+    parts: &[
+        Part { part: 1, desc: "Lowest distance", solve: part1 },
+        Part { part: 2, desc: "Highest distance", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let (cities, graph) = parse_graph(&input.numbered_lines())?;
+
+    Ok(solution(&cities, best_route(&cities, &graph, Goal::Min)?))
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let (cities, graph) = parse_graph(&input.numbered_lines())?;
+
+    Ok(solution(&cities, best_route(&cities, &graph, Goal::Max)?))
+}
+
+fn solution(cities: &[String], route: Tour<usize>) -> Solution {
+    Solution::from(route.weight).with_detail("route", route_names(cities, &route.order))
+}
+
+/// Finds the shortest or longest route visiting every city. Fails if the
+/// cities aren't connected well enough for any route to visit them all
+pub fn best_route(cities: &[String], graph: &Graph<usize>, goal: Goal) -> Result<Tour<usize>, String> {
+    TourSearch::new(graph).goal(goal).best_with(|order, dist| {
+        trace!("Route: {}  Distance: {}", route_names(cities, order).join(" -> "), dist);
+    }).ok_or_else(|| "no route visits every city".to_string())
+}
+
+pub fn route_names(cities: &[String], order: &[usize]) -> Vec<String> {
+    order.iter().map(|&c| cities[c].clone()).collect()
+}
+
+pub fn parse_graph(lines: &[Line]) -> Result<(Vec<String>, Graph<usize>), ParseError> {
+    let mut edges = Vec::new();
+    let mut cities: Vec<String> = Vec::new();
+    let mut index = HashMap::new();
+
+    let re = Regex::new(r"^(.*) to (.*) = (.*)").unwrap();
+
+    for l in lines {
+        let caps = re.captures(l.text)
+            .ok_or_else(|| l.line_error("\"<city> to <city> = <distance>\""))?;
+
+        let mut city = |name: &str| *index.entry(name.to_string()).or_insert_with(|| {
+            cities.push(name.to_string());
+            cities.len() - 1
+        });
+
+        let from = city(&caps[1]);
+        let to = city(&caps[2]);
+
+        edges.push((from, to, l.parse::<usize>(&caps[3], "a distance")?));
+    }
+
+    let mut graph = Graph::new(cities.len());
+
+    for (from, to, dist) in edges {
+        graph.set_both(from, to, dist);
+    }
+
+    Ok((cities, graph))
+}
+
+#[test]
+fn test_best_route() {
+    let input = Input::from("London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141\n");
+    let (cities, graph) = parse_graph(&input.numbered_lines()).unwrap();
+
+    let shortest = best_route(&cities, &graph, Goal::Min).unwrap();
+    assert!(shortest.weight == 605);
+    assert!(route_names(&cities, &shortest.order) == vec!["London", "Dublin", "Belfast"]);
+
+    let longest = best_route(&cities, &graph, Goal::Max).unwrap();
+    assert!(longest.weight == 982);
+    assert!(route_names(&cities, &longest.order) == vec!["Dublin", "London", "Belfast"]);
+}
+
+#[test]
+fn test_route_errors() {
+    // Dublin and Belfast are joined, but there's no way to London
+    let input = Input::from("London to Paris = 344\nDublin to Belfast = 141\n");
+    let (cities, graph) = parse_graph(&input.numbered_lines()).unwrap();
+
+    assert!(best_route(&cities, &graph, Goal::Min).unwrap_err() == "no route visits every city");
+
+    let input = Input::from("London to Dublin = 464\nLondon to Belfast\n");
+
+    let err = parse_graph(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 2 && err.column == 1 && err.text == "London to Belfast" && err.expected == "\"<city> to <city> = <distance>\"");
+
+    let input = Input::from("London to Dublin = far\n");
+
+    let err = parse_graph(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 20 && err.text == "far" && err.expected == "a distance");
+}
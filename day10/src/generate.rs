@@ -0,0 +1,29 @@
+//! Random look and say start sequences
+
+use aoc_lib::Rng;
+
+/// Generates a start sequence of size digits from 1 to 3 with no digit
+/// repeated more than three times
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    let mut run = 0;
+
+    while text.len() < size.max(1) {
+        let digit = rng.string(1, "123");
+
+        if text.ends_with(&digit) {
+            if run == 3 {
+                continue
+            }
+
+            run += 1;
+        } else {
+            run = 1;
+        }
+
+        text += &digit;
+    }
+
+    text.push('\n');
+    text
+}
@@ -0,0 +1,73 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult};
+
+pub const DAY: Day = Day {
+    day: 10,
+    input: Some("input10.txt"),
+    parts: &[
+        Part { part: 1, desc: "Length after 40 iterations", solve: part1 },
+        Part { part: 2, desc: "Length after 50 iterations", solve: part2 },
+    ],
+    params: &[
+        Param { name: "start", desc: "Starting sequence" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let start = load_start(input)?;
+
+    Ok(iterate(&start, 40).len().into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let start = load_start(input)?;
+
+    Ok(iterate(&start, 50).len().into())
+}
+
+fn load_start(input: &Input) -> Result<String, Box<dyn std::error::Error>> {
+    let start: String = input.param_or_input("start")?;
+
+    if start.is_empty() || !start.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Starting sequence must be digits ({})", start).into())
+    }
+
+    Ok(start)
+}
+
+pub fn iterate(start: &str, iterations: usize) -> String {
+    let mut string = start.to_string();
+
+    for _ in 0..iterations {
+        string = transform(string);
+    }
+
+    string
+}
+
+pub fn transform(string: String) -> String {
+    let chars: Vec<char> = string.chars().collect();
+    let mut last = chars[0];
+    let mut count: usize = 1;
+    let mut out_chars = Vec::new();
+
+    let mut add = |char, count: usize| {
+        let mut count_chars: Vec<char> = count.to_string().chars().collect();
+        out_chars.append(&mut count_chars);
+        out_chars.push(char);
+    };
+
+    for c in chars.iter().skip(1) {
+        if *c != last {
+            add(last, count);
+            count = 0;
+            last = *c;
+        }
+        count += 1;
+    }
+    add(last, count);
+
+    out_chars.iter().collect()
+}
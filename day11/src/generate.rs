@@ -0,0 +1,15 @@
+//! Random passwords
+
+use aoc_lib::Rng;
+
+/// Generates a password of size lower case letters, at least 6. The first
+/// letter is early in the alphabet so the search for the next two valid
+/// passwords never runs out of letters, and the forbidden 'i', 'o' and 'l'
+/// are never used
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = rng.string(1, "abcdefgh");
+    text += &rng.string(size.max(6) - 1, "abcdefghjkmnpqrstuvwxyz");
+
+    text.push('\n');
+    text
+}
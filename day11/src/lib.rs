@@ -0,0 +1,131 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult};
+
+pub const DAY: Day = Day {
+    day: 11,
+    input: Some("input11.txt"),
+    parts: &[
+        Part { part: 1, desc: "Next password", solve: part1 },
+        Part { part: 2, desc: "Next password after that", solve: part2 },
+    ],
+    params: &[
+        Param { name: "password", desc: "Current password" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let start_pwd = load_password(input)?;
+
+    Ok(calc_next_password(&start_pwd).into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let start_pwd = load_password(input)?;
+
+    let pwd = calc_next_password(&start_pwd);
+
+    Ok(calc_next_password(&pwd).into())
+}
+
+fn load_password(input: &Input) -> Result<String, Box<dyn std::error::Error>> {
+    let pwd: String = input.param_or_input("password")?;
+
+    if pwd.len() < 3 || !pwd.chars().all(|c| ALPHABET.contains(c)) {
+        return Err(format!("Password must be at least 3 characters from {} ({})", ALPHABET, pwd).into())
+    }
+
+    Ok(pwd)
+}
+
+const ALPHABET: &str = "abcdefghjkmnpqrstuvwxyz";
+
+pub fn calc_next_password(pwd: &str) -> String {
+    // Convert to numbers
+    let mut pwdnum: Vec<u8> = pwd.chars().map(|c| ALPHABET.find(c).unwrap() as u8).collect();
+
+    loop {
+        // Increment the password
+        increment_pwdnum(&mut pwdnum);
+
+        // Validate it
+        if validate_pwdnum(&pwdnum) {
+            break
+        }
+    }
+
+    pwdnum.iter().map(|n| ALPHABET.chars().nth(*n as usize).unwrap()).collect()
+}
+
+fn increment_pwdnum(pwdnum: &mut [u8]) {
+    let charcnt = pwdnum.len();
+    let alen = ALPHABET.len() as u8;
+
+    let mut curchar = charcnt - 1;
+    loop {
+        let newval = pwdnum[curchar] + 1;
+
+        if newval < alen {
+            pwdnum[curchar] = newval;
+            break
+        }
+
+        pwdnum[curchar] = 0;
+        curchar -= 1;
+    }
+}
+
+fn validate_pwdnum(pwdnum: &[u8]) -> bool {
+    let charcnt = pwdnum.len();
+
+    let mut got_triple = false;
+
+    for i in 0..charcnt - 2 {
+        if pwdnum[i + 1] == pwdnum[i] + 1 && pwdnum[i + 2] == pwdnum[i] + 2 {
+            got_triple = true;
+            break
+        }
+    }
+
+    if !got_triple {
+        return false
+    }
+
+    let mut got_pair1 = false;
+    let mut pair1_pos = 0;
+    let mut pair1_char = 0;
+
+    for i in 0..charcnt - 1 {
+        if pwdnum[i] == pwdnum[i + 1] {
+            got_pair1 = true;
+            pair1_pos = i;
+            pair1_char = pwdnum[i];
+            break
+        }
+    }
+
+    if !got_pair1 {
+        return false
+    }
+
+    let mut got_pair2 = false;
+
+    for i in pair1_pos..charcnt - 1 {
+        if pwdnum[i] == pwdnum[i + 1] && pwdnum[i] != pair1_char {
+            got_pair2 = true;
+            break
+        }
+    }
+
+    if !got_pair2 {
+        return false
+    }
+
+    true
+}
+
+#[test]
+fn test_calc_next_password() {
+    assert!(calc_next_password("abcdefgh") == "abcdffaa");
+}
@@ -0,0 +1,54 @@
+//! Random JSON documents
+
+use aoc_lib::Rng;
+
+/// Generates a JSON document with about size values in nested arrays and
+/// objects, some of which contain "red"
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    let mut budget = size.max(1) as i64;
+
+    value(rng, &mut text, &mut budget, 0);
+
+    text.push('\n');
+    text
+}
+
+fn value(rng: &mut Rng, text: &mut String, budget: &mut i64, depth: usize) {
+    *budget -= 1;
+
+    let choice = if depth == 0 { rng.below(2) } else if *budget <= 0 || depth > 8 { 2 + rng.below(2) } else { rng.below(4) };
+
+    match choice {
+        0 => {
+            text.push('[');
+
+            for i in 0..rng.range(1..=6) {
+                if i > 0 {
+                    text.push(',');
+                }
+
+                value(rng, text, budget, depth + 1);
+            }
+
+            text.push(']');
+        }
+        1 => {
+            text.push('{');
+
+            for i in 0..rng.range(1..=6) {
+                if i > 0 {
+                    text.push(',');
+                }
+
+                // Keys are distinct letters
+                text.push_str(&format!("\"{}\":", (b'a' + i as u8) as char));
+                value(rng, text, budget, depth + 1);
+            }
+
+            text.push('}');
+        }
+        2 => text.push_str(&rng.range(-50..=200).to_string()),
+        _ => text.push_str(&format!("\"{}\"", rng.choose(&["red", "green", "blue", "orange", "violet", "yellow"])))
+    }
+}
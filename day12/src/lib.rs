@@ -0,0 +1,86 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Part, PartResult};
+use json::JsonValue;
+
+pub const DAY: Day = Day {
+    day: 12,
+    input: Some("input12.txt"),
+    parts: &[
+        Part { part: 1, desc: "Total", solve: part1 },
+        Part { part: 2, desc: "Total without red", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let jsondoc = json::parse(input.text())?;
+
+    Ok((walknode1(&jsondoc) as i64).into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let jsondoc = json::parse(input.text())?;
+
+    Ok((walknode2(&jsondoc) as i64).into())
+}
+
+pub fn walknode1(node: &JsonValue) -> f64 {
+    let mut total: f64 = 0.0;
+
+    match node {
+        JsonValue::Object(obj) => {
+            for (_key, value) in obj.iter() {
+                total += walknode1(value);
+            }
+        },
+        JsonValue::Array(arr) => {
+            for node in arr {
+                total += walknode1(node);
+            }            
+        },
+        JsonValue::Number(num) => {
+            let rust_num: f64 = (*num).into();
+            total += rust_num;
+        },
+        _ => {}
+    }
+
+    total
+}
+
+pub fn walknode2(node: &JsonValue) -> f64 {
+    let mut total: f64 = 0.0;
+
+    match node {
+        JsonValue::Object(obj) => {
+            let mut skip = false;
+
+            for (_key, value) in obj.iter() {
+                if value == "red" {
+                    skip = true;
+                    break
+                }
+            }
+
+            if !skip {
+                for (_key, value) in obj.iter() {
+                    total += walknode2(value);
+                }
+            }
+        },
+        JsonValue::Array(arr) => {
+            for node in arr {
+                total += walknode2(node);
+            }            
+        },
+        JsonValue::Number(num) => {
+            let rust_num: f64 = (*num).into();
+            total += rust_num;
+        },
+        _ => {}
+    }
+
+    total
+}
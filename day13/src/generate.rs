@@ -0,0 +1,32 @@
+//! Random happiness lists
+
+use aoc_lib::Rng;
+
+const PEOPLE: [&str; 10] = ["Alice", "Bob", "Carol", "David", "Eric", "Frank", "George", "Mallory",
+    "Oscar", "Peggy"];
+
+/// Generates the happiness each of size people feels sitting next to each
+/// other person
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let people = names(size.max(2));
+    let mut text = String::new();
+
+    for person in &people {
+        for other in people.iter().filter(|o| *o != person) {
+            let happiness = rng.range(-100..=100);
+            let action = if happiness < 0 { "lose" } else { "gain" };
+
+            text += &format!("{} would {} {} happiness units by sitting next to {}.\n", person, action,
+                happiness.abs(), other);
+        }
+    }
+
+    text
+}
+
+fn names(count: usize) -> Vec<String> {
+    (0..count).map(|i| match i / PEOPLE.len() {
+        0 => PEOPLE[i].to_string(),
+        n => format!("{}{}", PEOPLE[i % PEOPLE.len()], n + 1)
+    }).collect()
+}
@@ -0,0 +1,177 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult, Solution, trace};
+use regex::Regex;
+use std::collections::HashMap;
+use tour_lib::{Goal, Graph, Shape, Tour, TourSearch};
+
+pub const DAY: Day = Day {
+    day: 13,
+    input: Some("input13.txt"),
+    parts: &[
+        Part { part: 1, desc: "Optimal happiness", solve: part1 },
+        Part { part: 2, desc: "Optimal happiness including me", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let (people, happiness) = parse_graph(&input.numbered_lines())?;
+
+    Ok(solution(&people, optimal_seating(&people, &happiness)?))
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let (people, happiness) = parse_graph(&input.numbered_lines())?;
+    let (people, happiness) = with_me(&people, &happiness);
+
+    Ok(solution(&people, optimal_seating(&people, &happiness)?))
+}
+
+fn solution(people: &[String], seating: Tour<i32>) -> Solution {
+    Solution::from(seating.weight).with_detail("seating", seating_names(people, &seating.order))
+}
+
+/// Finds the circular seating with the highest total happiness, given the
+/// happiness each person gains from sitting next to each other person. Fails if
+/// no seating has happiness known for every pair of neighbours
+pub fn optimal_seating(people: &[String], happiness: &Graph<i32>) -> Result<Tour<i32>, String> {
+    // Both neighbours' happiness counts towards each pair sitting together
+    let mut pairs = Graph::new(happiness.len());
+
+    for a in 0..happiness.len() {
+        for b in a + 1..happiness.len() {
+            if let (Some(ab), Some(ba)) = (happiness.get(a, b), happiness.get(b, a)) {
+                pairs.set_both(a, b, ab + ba);
+            }
+        }
+    }
+
+    TourSearch::new(&pairs).shape(Shape::Cycle).goal(Goal::Max).best_with(|order, total| {
+        trace!("{}, happiness {}", seating_names(people, order).join(" - "), total);
+    }).ok_or_else(|| "no seating has happiness for every pair of neighbours".to_string())
+}
+
+/// Adds me to the guest list, neither gaining nor causing any happiness
+pub fn with_me(people: &[String], happiness: &Graph<i32>) -> (Vec<String>, Graph<i32>) {
+    let me = people.len();
+
+    let mut new_people = people.to_vec();
+    new_people.push("Me".to_string());
+
+    let mut new_happiness = Graph::new(me + 1);
+
+    for a in 0..me {
+        for b in 0..me {
+            if let Some(h) = happiness.get(a, b) {
+                new_happiness.set(a, b, h);
+            }
+        }
+
+        new_happiness.set_both(a, me, 0);
+    }
+
+    (new_people, new_happiness)
+}
+
+pub fn seating_names(people: &[String], order: &[usize]) -> Vec<String> {
+    order.iter().map(|&p| people[p].clone()).collect()
+}
+
+pub fn parse_graph(lines: &[Line]) -> Result<(Vec<String>, Graph<i32>), ParseError> {
+    let mut edges = Vec::new();
+    let mut people: Vec<String> = Vec::new();
+
+    let re = Regex::new(r"^(.*) would (.*) (.*) happiness units by sitting next to (.*).").unwrap();
+
+    for l in lines {
+        let caps = re.captures(l.text)
+            .ok_or_else(|| l.line_error("\"<name> would gain|lose <n> happiness units by sitting next to <name>.\""))?;
+
+        let mut happiness: i32 = l.parse(&caps[3], "a number of happiness units")?;
+
+        match &caps[2] {
+            "gain" => {}
+            "lose" => happiness = -happiness,
+            other => return Err(l.error(other, "\"gain\" or \"lose\""))
+        }
+
+        edges.push((caps[1].to_string(), caps[4].to_string(), happiness));
+
+        if !people.iter().any(|p| *p == caps[1]) {
+            people.push(caps[1].to_string());
+        }
+    }
+
+    people.sort();
+
+    let index: HashMap<&str, usize> = people.iter().enumerate().map(|(i, p)| (p.as_str(), i)).collect();
+    let mut graph = Graph::new(people.len());
+
+    for (person1, person2, happiness) in &edges {
+        if let (Some(&a), Some(&b)) = (index.get(person1.as_str()), index.get(person2.as_str())) {
+            graph.set(a, b, *happiness);
+        }
+    }
+
+    Ok((people, graph))
+}
+
+#[test]
+fn test_optimal_seating() {
+    let lines = [
+        "Alice would gain 54 happiness units by sitting next to Bob.",
+        "Alice would lose 79 happiness units by sitting next to Carol.",
+        "Alice would lose 2 happiness units by sitting next to David.",
+        "Bob would gain 83 happiness units by sitting next to Alice.",
+        "Bob would lose 7 happiness units by sitting next to Carol.",
+        "Bob would lose 63 happiness units by sitting next to David.",
+        "Carol would lose 62 happiness units by sitting next to Alice.",
+        "Carol would gain 60 happiness units by sitting next to Bob.",
+        "Carol would gain 55 happiness units by sitting next to David.",
+        "David would gain 46 happiness units by sitting next to Alice.",
+        "David would lose 7 happiness units by sitting next to Bob.",
+        "David would gain 41 happiness units by sitting next to Carol."
+    ];
+
+    let lines: Vec<Line> = lines.iter().enumerate().map(|(i, l)| Line::new(i + 1, l)).collect();
+    let (people, happiness) = parse_graph(&lines).unwrap();
+
+    let seating = optimal_seating(&people, &happiness).unwrap();
+    assert!(seating.weight == 330);
+    assert!(seating_names(&people, &seating.order) == vec!["Alice", "Bob", "Carol", "David"]);
+
+    let (people, happiness) = with_me(&people, &happiness);
+    assert!(people.len() == 5 && optimal_seating(&people, &happiness).unwrap().weight == 286);
+}
+
+#[test]
+fn test_no_seating() {
+    // Alice and Bob don't say how they feel about Carol
+    let input = Input::from("Alice would gain 5 happiness units by sitting next to Bob.\n\
+        Bob would gain 5 happiness units by sitting next to Alice.\n\
+        Carol would gain 5 happiness units by sitting next to Alice.\n");
+
+    let (people, happiness) = parse_graph(&input.numbered_lines()).unwrap();
+    assert!(optimal_seating(&people, &happiness).unwrap_err() == "no seating has happiness for every pair of neighbours");
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("Alice would gain 54 happiness units by sitting next to Bob.\n\
+        Bob would win 3 happiness units by sitting next to Alice.\n");
+
+    let err = parse_graph(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 2 && err.column == 11 && err.text == "win" && err.expected == "\"gain\" or \"lose\"");
+
+    let input = Input::from("Alice sits next to Bob.\n");
+
+    let err = parse_graph(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 1 && err.text == "Alice sits next to Bob." && err.expected.starts_with("\"<name> would gain|lose"));
+
+    let input = Input::from("Alice would gain x happiness units by sitting next to Bob.\n");
+
+    let err = parse_graph(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 18 && err.text == "x" && err.expected == "a number of happiness units");
+}
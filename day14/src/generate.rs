@@ -0,0 +1,23 @@
+//! Random reindeer specifications
+
+use aoc_lib::Rng;
+
+const REINDEER: [&str; 9] = ["Dasher", "Dancer", "Prancer", "Vixen", "Comet", "Cupid", "Donner",
+    "Blitzen", "Rudolph"];
+
+/// Generates the flying and resting times of size reindeer
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for i in 0..size.max(1) {
+        let name = match i / REINDEER.len() {
+            0 => REINDEER[i].to_string(),
+            n => format!("{}{}", REINDEER[i % REINDEER.len()], n + 1)
+        };
+
+        text += &format!("{} can fly {} km/s for {} seconds, but then must rest for {} seconds.\n", name,
+            rng.range(5..=25), rng.range(2..=15), rng.range(20..=170));
+    }
+
+    text
+}
@@ -0,0 +1,184 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, Param, ParseError, Part, PartResult, Solution};
+use regex::Regex;
+use std::cmp::{min, Ordering};
+
+pub const RACE_DUR: u32 = 2503;
+
+pub const DAY: Day = Day {
+    day: 14,
+    input: Some("input14.txt"),
+    parts: &[
+        Part { part: 1, desc: "Max distance", solve: part1 },
+        Part { part: 2, desc: "Max points", solve: part2 },
+    ],
+    params: &[
+        Param { name: "duration", desc: "Race duration in seconds" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let reindeer = parse_reindeer(&input.numbered_lines())?;
+    let race_dur = input.param_or("duration", RACE_DUR)?;
+
+    let dists: Vec<u32> = reindeer.iter().map(|r| r.distance(race_dur)).collect();
+
+    let max_dist = *dists.iter().max().ok_or("No reindeer in the race")?;
+
+    Ok(Solution::from(max_dist).with_detail("distances", dists))
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let reindeer = parse_reindeer(&input.numbered_lines())?;
+    let race_dur = input.param_or("duration", RACE_DUR)?;
+
+    let points: Vec<u32> = race(&reindeer, race_dur).iter().map(|rs| rs.points).collect();
+    let max_points = *points.iter().max().ok_or("No reindeer in the race")?;
+
+    Ok(Solution::from(max_points).with_detail("points", points))
+}
+
+/// A reindeer's position and points at the end of a race
+pub struct Standing {
+    pub dist: u32,
+    pub points: u32
+}
+
+struct ReindeerState<'a> {
+    moving: bool,
+    time_left: u32,
+    dist: u32,
+    points: u32,
+    reindeer: &'a Reindeer,
+}
+
+/// Simulates a race second by second, awarding a point to the leaders at the
+/// end of each second
+pub fn race(reindeer: &[Reindeer], race_dur: u32) -> Vec<Standing> {
+    let mut state: Vec<ReindeerState> = reindeer.iter().map(|r| {
+        ReindeerState {
+            moving: true,
+            time_left: r.travel_dur,
+            dist: 0,
+            points: 0,
+            reindeer: r,
+        }
+    }).collect();
+
+    for _ in 0..race_dur {
+        // Calculate next seconds
+        for rs in &mut state {
+            if rs.moving {
+                rs.dist += rs.reindeer.speed;
+            }
+
+            rs.time_left -= 1;
+            if rs.time_left == 0 {
+                if rs.moving {
+                    rs.moving = false;
+                    rs.time_left = rs.reindeer.rest_dur;
+                } else {
+                    rs.moving = true;
+                    rs.time_left = rs.reindeer.travel_dur;
+                }
+            }
+        }
+
+        // Find out who is winning
+        let (leaders, _) = state.iter().enumerate()
+            .fold((Vec::new(), 0), |(mut leaders, lead_dist), (idx, rs)| {
+                match rs.dist.cmp(&lead_dist) {
+                    Ordering::Greater => (vec![idx], rs.dist),
+                    Ordering::Equal => {
+                        leaders.push(idx);
+                        (leaders, lead_dist)    
+                    }
+                    Ordering::Less => (leaders, lead_dist),
+                }
+            }
+        );
+
+        for leader in leaders {
+            state[leader].points += 1;
+        }
+    }
+
+    state.iter().map(|rs| Standing { dist: rs.dist, points: rs.points }).collect()
+}
+
+#[derive(Debug)]
+pub struct Reindeer {
+    pub speed: u32,
+    pub travel_dur: u32,
+    pub rest_dur: u32,
+    pub total_dur: u32
+}
+
+impl Reindeer {
+    pub fn new(speed: u32, travel_dur: u32, rest_dur: u32) -> Self {
+        Self {
+            speed,
+            travel_dur,
+            rest_dur,
+            total_dur: travel_dur + rest_dur
+        }
+    }
+
+    /// Returns the distance flown after a number of seconds
+    pub fn distance(&self, secs: u32) -> u32 {
+        let tot_times = secs / self.total_dur;
+        let remainder = secs % self.total_dur;
+
+        let mut dist = tot_times * self.speed * self.travel_dur;
+        dist += self.speed * min(self.travel_dur, remainder);
+
+        dist
+    }
+}
+
+pub fn parse_reindeer(lines: &[Line]) -> Result<Vec<Reindeer>, ParseError> {
+    let re = Regex::new(r"^.* can fly (.*) km/s for (.*) seconds, but then must rest for (.*) seconds.").unwrap();
+
+    lines.iter().map(|l| {
+        let caps = re.captures(l.text)
+            .ok_or_else(|| l.line_error("\"<name> can fly <n> km/s for <n> seconds, but then must rest for <n> seconds.\""))?;
+
+        // Both times must be at least a second for the reindeer to take turns
+        let secs = |n: usize| l.parse::<u32>(&caps[n], "a whole number of seconds above 0")
+            .and_then(|secs| if secs > 0 { Ok(secs) } else { Err(l.error(&caps[n], "a whole number of seconds above 0")) });
+
+        Ok(Reindeer::new(l.parse(&caps[1], "a whole number of km/s")?, secs(2)?, secs(3)?))
+    }).collect()
+}
+
+#[test]
+fn test_race() {
+    let mut input = Input::from("Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
+        Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.\n");
+    input.set_param("duration", "1000");
+
+    assert!(part1(&input).unwrap().answer == 1120u32.into());
+    assert!(part2(&input).unwrap().answer == 689u32.into());
+
+    assert!(part1(&Input::from("")).unwrap_err().to_string() == "No reindeer in the race");
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\nDancer can run fast.\n");
+
+    let err = parse_reindeer(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 3 && err.column == 1 && err.text == "Dancer can run fast." && err.expected.starts_with("\"<name> can fly"));
+
+    let input = Input::from("Comet can fly 14 km/s for 0 seconds, but then must rest for 127 seconds.\n");
+
+    let err = parse_reindeer(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 27 && err.text == "0" && err.expected == "a whole number of seconds above 0");
+
+    let input = Input::from("Comet can fly fast km/s for 10 seconds, but then must rest for 127 seconds.\n");
+
+    let err = parse_reindeer(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 15 && err.text == "fast" && err.expected == "a whole number of km/s");
+}
@@ -0,0 +1,28 @@
+//! Random ingredient lists
+
+use aoc_lib::Rng;
+
+const INGREDIENTS: [&str; 8] = ["Sugar", "Sprinkles", "Candy", "Chocolate", "Butterscotch", "Cinnamon",
+    "Frosting", "Butter"];
+
+/// Generates size ingredients, at least 2. Like the puzzle input, each
+/// ingredient adds to one property and takes away from the others, which
+/// keeps the scores within range
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for i in 0..size.max(2) {
+        let name = match i / INGREDIENTS.len() {
+            0 => INGREDIENTS[i].to_string(),
+            n => format!("{}{}", INGREDIENTS[i % INGREDIENTS.len()], n + 1)
+        };
+
+        let good = i % 4;
+        let props: Vec<i64> = (0..4).map(|p| if p == good { rng.range(2..=5) } else { rng.range(-3..=0) }).collect();
+
+        text += &format!("{}: capacity {}, durability {}, flavor {}, texture {}, calories {}\n", name,
+            props[0], props[1], props[2], props[3], rng.range(1..=9));
+    }
+
+    text
+}
@@ -0,0 +1,211 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, Param, ParseError, Part, PartResult};
+use regex::Regex;
+use std::ops::{AddAssign, Mul};
+
+pub const TEASPOONS: usize = 100;
+pub const CALORIE_TARGET: usize = 500;
+
+pub const DAY: Day = Day {
+    day: 15,
+    input: Some("input15.txt"),
+    parts: &[
+        Part { part: 1, desc: "Max total", solve: part1 },
+        Part { part: 2, desc: "Max calorie target total", solve: part2 },
+    ],
+    params: &[
+        Param { name: "teaspoons", desc: "Total teaspoons of ingredients" },
+        Param { name: "calories", desc: "Calorie target" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let ingredients = parse_ingredients(&input.numbered_lines())?;
+    let teaspoons = input.param_or("teaspoons", TEASPOONS)?;
+    let calorie_target = input.param_or("calories", CALORIE_TARGET)?;
+
+    let (max_total, _) = process(&ingredients, teaspoons, calorie_target);
+
+    Ok(max_total.into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let ingredients = parse_ingredients(&input.numbered_lines())?;
+    let teaspoons = input.param_or("teaspoons", TEASPOONS)?;
+    let calorie_target = input.param_or("calories", CALORIE_TARGET)?;
+
+    let (_, max_calorie_total) = process(&ingredients, teaspoons, calorie_target);
+
+    Ok(max_calorie_total.into())
+}
+
+pub fn process(ingredients: &[Qtys], teaspoons: usize, calorie_target: usize) -> (i32, i32) {
+    let mut max_total = 0;
+    let mut max_calorie_total = 0;
+
+    for quantities in partitioner(ingredients.len(), teaspoons) {
+        let mut tot_qtys: Qtys = Default::default();
+
+        for (qty, ingredient) in quantities.iter().zip(ingredients) {
+            tot_qtys += ingredient * *qty as i32;
+        }
+
+        let total = tot_qtys.quality_product();
+
+        if total > max_total {
+            max_total = total
+        }
+
+        if tot_qtys.calories == calorie_target as i32 && total > max_calorie_total {
+            max_calorie_total = total
+        }
+    }
+
+    (max_total, max_calorie_total)
+}
+
+#[derive(Debug, Default)]
+pub struct Qtys {
+    pub capacity: i32,
+    pub durability: i32,
+    pub flavor: i32,
+    pub texture: i32,
+    pub calories: i32
+}
+
+impl Qtys {
+    fn quality_product(&self) -> i32 {
+        if self.capacity < 0 || self.durability < 0 || self.flavor < 0 || self.texture < 0 {
+            0
+        } else {
+            self.capacity * self.durability * self.flavor * self.texture
+        }
+    }
+}
+
+impl AddAssign for Qtys {
+    fn add_assign(&mut self, other: Self) {
+        *self = Self {
+            capacity: self.capacity + other.capacity,
+            durability: self.durability + other.durability,
+            flavor: self.flavor + other.flavor,
+            texture: self.texture + other.texture,
+            calories: self.calories + other.calories,
+        };
+    }
+}
+
+impl Mul<i32> for &Qtys {
+    type Output = Qtys;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Qtys {
+            capacity: self.capacity * rhs,
+            durability: self.durability * rhs,
+            flavor: self.flavor * rhs,
+            texture: self.texture * rhs,
+            calories: self.calories * rhs,
+        }
+    }
+}
+
+/// Parses the ingredients, of which there must be at least two to share out
+/// the teaspoons
+pub fn parse_ingredients(lines: &[Line]) -> Result<Vec<Qtys>, ParseError> {
+    let re = Regex::new(r"^(.*): capacity (.*), durability (.*), flavor (.*), texture (.*), calories (.*)").unwrap();
+
+    let ingredients = lines.iter().map(|l| {
+        let caps = re.captures(l.text)
+            .ok_or_else(|| l.line_error("\"<name>: capacity <n>, durability <n>, flavor <n>, texture <n>, calories <n>\""))?;
+
+        let qty = |n: usize, name: &str| l.parse::<i32>(&caps[n], &format!("a whole number of {}", name));
+
+        Ok(Qtys {
+            capacity: qty(2, "capacity")?,
+            durability: qty(3, "durability")?,
+            flavor: qty(4, "flavor")?,
+            texture: qty(5, "texture")?,
+            calories: qty(6, "calories")?
+        })
+    }).collect::<Result<Vec<_>, _>>()?;
+
+    if ingredients.len() < 2 {
+        let end = lines.last().map_or(1, |l| l.number + 1);
+        return Err(Line::new(end, "").line_error("at least two ingredients"))
+    }
+
+    Ok(ingredients)
+}
+
+pub struct Partitioner {
+    vector: Vec<usize>,
+    partitions: usize,
+    size: usize
+}
+
+impl Iterator for Partitioner {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let mut item = self.partitions - 2;
+
+        loop {
+            self.vector[item] += 1;
+
+            let sum: usize = self.vector.iter().take(self.partitions - 1).sum();
+
+            if sum < self.size {
+                self.vector[self.partitions - 1] = self.size - sum;
+                break
+            }
+
+            if item == 0 {
+                return None
+            }
+
+            self.vector[item] = 1;
+            item -= 1;
+        }
+    
+        Some(self.vector.clone())
+    }
+}
+
+pub fn partitioner(partitions: usize, size: usize) -> Partitioner {
+    let mut vector = Vec::with_capacity(partitions);
+
+    for _ in 0..partitions - 2 {
+        vector.push(1)
+    }
+    vector.push(0);
+    vector.push(0);
+
+    Partitioner {
+        vector,
+        partitions,
+        size,
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8\n\n\
+        Cinnamon: capacity 2, durability 3, flavor -2, texture x, calories 3\n");
+
+    let err = parse_ingredients(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 3 && err.column == 56 && err.text == "x" && err.expected == "a whole number of texture");
+
+    let input = Input::from("Butterscotch: capacity -1\n");
+
+    let err = parse_ingredients(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 1 && err.text == "Butterscotch: capacity -1" && err.expected.starts_with("\"<name>: capacity"));
+
+    // One ingredient can't be shared out
+    let input = Input::from("Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8\n");
+
+    let err = parse_ingredients(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 2 && err.expected == "at least two ingredients");
+    assert!(part1(&input).is_err() && part1(&Input::from("")).is_err());
+}
@@ -0,0 +1,48 @@
+//! Random lists of aunts
+
+use crate::{match_sue1, match_sue2, parse_sues};
+use aoc_lib::{Line, Rng};
+
+const COMPOUNDS: [&str; 10] = ["children", "cats", "samoyeds", "pomeranians", "akitas", "vizslas",
+    "goldfish", "trees", "cars", "perfumes"];
+
+/// Generates size aunts, at least 2, each with three compounds. Exactly one
+/// aunt matches the ticker tape readings exactly, and exactly one other
+/// matches them with the ranges of part 2
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(2);
+
+    // Pick where the matching aunts go
+    let exact = rng.index(count);
+    let ranged = (exact + 1 + rng.index(count - 1)) % count;
+
+    let mut text = String::new();
+
+    for n in 1..=count {
+        let compounds = loop {
+            let compounds = random_compounds(rng);
+            let line = format!("Sue {}: {}", n, compounds);
+            let sue = &parse_sues(&[Line::new(n, &line)]).unwrap()[0];
+
+            let wanted = (n - 1 == exact, n - 1 == ranged);
+
+            if (match_sue1(sue), match_sue2(sue)) == wanted {
+                break compounds
+            }
+        };
+
+        text += &format!("Sue {}: {}\n", n, compounds);
+    }
+
+    text
+}
+
+/// Returns three different compounds with quantities
+fn random_compounds(rng: &mut Rng) -> String {
+    let mut compounds = COMPOUNDS.to_vec();
+    rng.shuffle(&mut compounds);
+
+    let terms: Vec<String> = compounds[..3].iter().map(|c| format!("{}: {}", c, rng.range(0..=10))).collect();
+
+    terms.join(", ")
+}
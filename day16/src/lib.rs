@@ -0,0 +1,207 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult};
+
+pub const TEST_RESULTS: Sue = Sue {
+    children: Some(3),
+    cats: Some(7),
+    samoyeds: Some(2),
+    pomeranians: Some(3),
+    akitas: Some(0),
+    vizslas: Some(0),
+    goldfish: Some(5),
+    trees: Some(3),
+    cars: Some(2),
+    perfumes: Some(1)
+};
+
+pub const DAY: Day = Day {
+    day: 16,
+    input: Some("input16.txt"),
+    parts: &[
+        Part { part: 1, desc: "Valid Sue", solve: part1 },
+        Part { part: 2, desc: "Valid Sue with ranges", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let sues = parse_sues(&input.numbered_lines())?;
+
+    Ok(find_sue(&sues, match_sue1)?.into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let sues = parse_sues(&input.numbered_lines())?;
+
+    Ok(find_sue(&sues, match_sue2)?.into())
+}
+
+/// Returns the number of the one Sue matching the analysis
+pub fn find_sue(sues: &[Sue], matches: fn(&Sue) -> bool) -> Result<usize, String> {
+    let valid_sues: Vec<usize> = sues.iter().enumerate().filter_map(|(idx, sue)| {
+        if matches(sue) {
+            Some(idx + 1)
+        } else {
+            None
+        }
+    }).collect();
+
+    match valid_sues[..] {
+        [sue] => Ok(sue),
+        _ => Err(format!("{} Sues match the analysis, expected exactly one", valid_sues.len()))
+    }
+}
+
+pub fn match_sue1(sue: &Sue) -> bool {
+    if let Some(children) = sue.children {
+        if children != TEST_RESULTS.children.unwrap() { return false }
+    }
+    if let Some(cats) = sue.cats {
+        if cats != TEST_RESULTS.cats.unwrap() { return false }
+    }
+    if let Some(samoyeds) = sue.samoyeds {
+        if samoyeds != TEST_RESULTS.samoyeds.unwrap() { return false }
+    }
+    if let Some(pomeranians) = sue.pomeranians {
+        if pomeranians != TEST_RESULTS.pomeranians.unwrap() { return false }
+    }
+    if let Some(akitas) = sue.akitas {
+        if akitas != TEST_RESULTS.akitas.unwrap() { return false }
+    }
+    if let Some(vizslas) = sue.vizslas {
+        if vizslas != TEST_RESULTS.vizslas.unwrap() { return false }
+    }
+    if let Some(goldfish) = sue.goldfish {
+        if goldfish != TEST_RESULTS.goldfish.unwrap() { return false }
+    }
+    if let Some(trees) = sue.trees {
+        if trees != TEST_RESULTS.trees.unwrap() { return false }
+    }
+    if let Some(cars) = sue.cars {
+        if cars != TEST_RESULTS.cars.unwrap() { return false }
+    }
+    if let Some(perfumes) = sue.perfumes {
+        if perfumes != TEST_RESULTS.perfumes.unwrap() { return false }
+    }
+
+    true
+}
+
+pub fn match_sue2(sue: &Sue) -> bool {
+    if let Some(children) = sue.children {
+        if children != TEST_RESULTS.children.unwrap() { return false }
+    }
+    if let Some(cats) = sue.cats {
+        if cats <= TEST_RESULTS.cats.unwrap() { return false }
+    }
+    if let Some(samoyeds) = sue.samoyeds {
+        if samoyeds != TEST_RESULTS.samoyeds.unwrap() { return false }
+    }
+    if let Some(pomeranians) = sue.pomeranians {
+        if pomeranians >= TEST_RESULTS.pomeranians.unwrap() { return false }
+    }
+    if let Some(akitas) = sue.akitas {
+        if akitas != TEST_RESULTS.akitas.unwrap() { return false }
+    }
+    if let Some(vizslas) = sue.vizslas {
+        if vizslas != TEST_RESULTS.vizslas.unwrap() { return false }
+    }
+    if let Some(goldfish) = sue.goldfish {
+        if goldfish >= TEST_RESULTS.goldfish.unwrap() { return false }
+    }
+    if let Some(trees) = sue.trees {
+        if trees <= TEST_RESULTS.trees.unwrap() { return false }
+    }
+    if let Some(cars) = sue.cars {
+        if cars != TEST_RESULTS.cars.unwrap() { return false }
+    }
+    if let Some(perfumes) = sue.perfumes {
+        if perfumes != TEST_RESULTS.perfumes.unwrap() { return false }
+    }
+
+    true
+}
+
+#[derive(Debug, Default)]
+pub struct Sue {
+    pub children: Option<u16>,
+    pub cats: Option<u16>,
+    pub samoyeds: Option<u16>,
+    pub pomeranians: Option<u16>,
+    pub akitas: Option<u16>,
+    pub vizslas: Option<u16>,
+    pub goldfish: Option<u16>,
+    pub trees: Option<u16>,
+    pub cars: Option<u16>,
+    pub perfumes: Option<u16>,
+}
+
+pub fn parse_sues(lines: &[Line]) -> Result<Vec<Sue>, ParseError> {
+    let mut sues = Vec::new();
+
+    for l in lines {
+        let (_, termstr) = l.text.split_once(": ")
+            .ok_or_else(|| l.line_error("\"Sue <n>: <compound>: <n>, ...\""))?;
+
+        let mut sue: Sue = Default::default();
+
+        for t in termstr.split(", ") {
+            let (key, value) = t.split_once(": ")
+                .ok_or_else(|| l.error(t, "\"<compound>: <n>\""))?;
+
+            let value = Some(l.parse::<u16>(value, "a compound quantity")?);
+
+            match key {
+                "children" => sue.children = value,
+                "cats" => sue.cats = value,
+                "samoyeds" => sue.samoyeds = value,
+                "pomeranians" => sue.pomeranians = value,
+                "akitas" => sue.akitas = value,
+                "vizslas" => sue.vizslas = value,
+                "goldfish" => sue.goldfish = value,
+                "trees" => sue.trees = value,
+                "cars" => sue.cars = value,
+                "perfumes" => sue.perfumes = value,
+                _ => return Err(l.error(key, "a compound name"))
+            }
+        }
+
+        sues.push(sue);
+    }
+
+    Ok(sues)
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("Sue 1: cars: 9, akitas: 3\nSue 2: dogs: 1\n");
+
+    let err = parse_sues(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 2 && err.column == 8 && err.text == "dogs" && err.expected == "a compound name");
+
+    let input = Input::from("Sue 1: cars 9\n");
+
+    let err = parse_sues(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 8 && err.text == "cars 9" && err.expected == "\"<compound>: <n>\"");
+
+    let input = Input::from("Sue 1\n\nSue 2: cars: -1\n");
+
+    let err = parse_sues(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 1 && err.text == "Sue 1");
+
+    let err = parse_sues(&input.numbered_lines()[1..]).unwrap_err();
+    assert!(err.line == 3 && err.column == 14 && err.text == "-1" && err.expected == "a compound quantity");
+}
+
+#[test]
+fn test_find_sue() {
+    let input = Input::from("Sue 1: cars: 9, akitas: 3\nSue 2: cars: 2, trees: 3\nSue 3: cars: 2\n");
+    let sues = parse_sues(&input.numbered_lines()).unwrap();
+
+    assert!(find_sue(&sues[..2], match_sue1) == Ok(2));
+    assert!(find_sue(&sues, match_sue1) == Err("2 Sues match the analysis, expected exactly one".to_string()));
+    assert!(find_sue(&sues[..1], match_sue1) == Err("0 Sues match the analysis, expected exactly one".to_string()));
+    assert!(find_sue(&sues, match_sue2) == Ok(3));
+}
@@ -0,0 +1,8 @@
+//! Random container sizes
+
+use aoc_lib::Rng;
+
+/// Generates the capacities of size containers
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| format!("{}\n", rng.range(5..=50))).collect()
+}
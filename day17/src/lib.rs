@@ -0,0 +1,76 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Part, PartResult, trace};
+use combi_lib::{BitSet, SubsetSum};
+
+const LITRES: u64 = 150;
+
+pub const DAY: Day = Day {
+    day: 17,
+    input: Some("input17.txt"),
+    parts: &[
+        Part { part: 1, desc: "Combinations", solve: part1 },
+        Part { part: 2, desc: "Combinations using fewest containers", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let capacities = load_capacities(input)?;
+
+    Ok(combinations(&capacities, LITRES).len().into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let capacities = load_capacities(input)?;
+
+    Ok(min_combinations(&capacities, LITRES).into())
+}
+
+pub fn load_capacities(input: &Input) -> Result<Vec<u16>, Box<dyn std::error::Error>> {
+    let mut capacities: Vec<u16> = input.parsed()?;
+    capacities.sort();
+
+    Ok(capacities)
+}
+
+pub fn combinations(capacities: &[u16], litres: u64) -> Vec<BitSet> {
+    let values: Vec<u64> = capacities.iter().map(|&c| c as u64).collect();
+
+    let answers = SubsetSum::new(&values, litres).subsets();
+
+    for a in &answers {
+        trace!("{}", a.join(capacities, "+"));
+    }
+
+    answers
+}
+
+pub fn min_combinations(capacities: &[u16], litres: u64) -> usize {
+    let values: Vec<u64> = capacities.iter().map(|&c| c as u64).collect();
+
+    match SubsetSum::new(&values, litres).min_size() {
+        Some(min) => SubsetSum::new(&values, litres).sizes(min..=min).count(),
+        None => 0
+    }
+}
+
+#[test]
+fn test_combinations() {
+    let mut capacities: Vec<u16> = vec![20, 15, 10, 5, 5];
+    capacities.sort();
+
+    let answers = combinations(&capacities, 25);
+
+    assert!(answers.len() == 4);
+
+    let lists: Vec<String> = answers.iter().map(|a| a.join(&capacities, "+")).collect();
+
+    assert!(lists[0] == "5+5+15");
+    assert!(lists[1] == "5+20");
+    assert!(lists[2] == "5+20");
+    assert!(lists[3] == "10+15");
+
+    assert!(min_combinations(&capacities, 25) == 3);
+}
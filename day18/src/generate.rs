@@ -0,0 +1,15 @@
+//! Random light grids
+
+use aoc_lib::Rng;
+
+/// Generates a square grid of lights size lights across
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for _ in 0..size.max(1) {
+        text += &rng.string(size.max(1), "#.");
+        text.push('\n');
+    }
+
+    text
+}
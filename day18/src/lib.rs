@@ -0,0 +1,104 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult};
+use grid_lib::{Adjacency, Coord, DenseGrid, Topology};
+
+pub type Board = DenseGrid<bool>;
+
+pub const DAY: Day = Day {
+    day: 18,
+    input: Some("input18.txt"),
+    parts: &[
+        Part { part: 1, desc: "Lights lit", solve: part1 },
+        Part { part: 2, desc: "Lights lit with stuck corners", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let mut board = load_board(input)?;
+
+    for _ in 0..100 {
+        board = step(&board)
+    }
+
+    Ok(board.count(|&lit| lit).into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let mut board = load_board(input)?;
+
+    fix_corners(&mut board);
+
+    for _ in 0..100 {
+        board = step(&board);
+
+        fix_corners(&mut board);
+    }
+
+    Ok(board.count(|&lit| lit).into())
+}
+
+/// Loads a board of # (lit) and . (unlit) lights, with every row the same
+/// width
+pub fn load_board(input: &Input) -> Result<Board, ParseError> {
+    let lines = input.numbered_lines();
+
+    let width = lines.first()
+        .ok_or_else(|| Line::new(1, "").line_error("rows of # and . lights"))?
+        .text.chars().count();
+
+    for l in &lines {
+        if let Some((pos, c)) = l.text.char_indices().find(|&(_, c)| c != '#' && c != '.') {
+            return Err(l.error(&l.text[pos..pos + c.len_utf8()], "# or ."))
+        }
+
+        if l.text.len() != width {
+            return Err(l.line_error(&format!("a row of {} lights", width)))
+        }
+    }
+
+    // The rows have all been checked to be the same width
+    let rows = lines.iter().map(|l| l.text.chars().map(|c| c == '#'));
+
+    Ok(DenseGrid::from_rows(rows).unwrap())
+}
+
+pub fn fix_corners(board: &mut Board) {
+    let max_x = board.width() as i32 - 1;
+    let max_y = board.height() as i32 - 1;
+
+    board.set(Coord::new(0, 0), true);
+    board.set(Coord::new(0, max_y), true);
+    board.set(Coord::new(max_x, 0), true);
+    board.set(Coord::new(max_x, max_y), true);
+}
+
+pub fn step(board: &Board) -> Board {
+    board.map(|c, &lit| {
+        let count = board.neighbours(c, Adjacency::Eight, Topology::Bounded)
+            .filter(|&n| board.get(n) == Some(&true))
+            .count();
+
+        matches!((lit, count), (true, 2) | (_, 3))
+    })
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from(".#.\n#.#\n");
+    assert!(load_board(&input).unwrap().count(|&lit| lit) == 3);
+
+    let input = Input::from(".#.\n\n#x#\n");
+
+    let err = load_board(&input).unwrap_err();
+    assert!(err.line == 3 && err.column == 2 && err.text == "x" && err.expected == "# or .");
+
+    let input = Input::from(".#.\n#.\n");
+
+    let err = load_board(&input).unwrap_err();
+    assert!(err.line == 2 && err.column == 1 && err.text == "#." && err.expected == "a row of 3 lights");
+
+    assert!(load_board(&Input::from("")).unwrap_err().expected == "rows of # and . lights");
+}
@@ -0,0 +1,68 @@
+//! Random replacement grammars and molecules
+
+use aoc_lib::Rng;
+
+/// Elements which can be replaced
+const ELEMENTS: [&str; 10] = ["Al", "B", "Ca", "F", "H", "Mg", "N", "O", "P", "Ti"];
+
+/// Generates replacements shaped like the puzzle input and a molecule built
+/// from "e" with size replacements. Like the puzzle input, replacements
+/// either produce two elements or wrap elements in "Rn", "Y" and "Ar", which
+/// are never replaced themselves
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut rules: Vec<(String, Vec<String>)> = Vec::new();
+
+    for from in std::iter::once("e").chain(ELEMENTS.iter().copied()) {
+        for _ in 0..rng.range(1..=3) {
+            // Each molecule can only be produced by one replacement
+            let to = loop {
+                let to = random_replacement(rng, from == "e");
+
+                if !rules.iter().any(|(_, t)| *t == to) {
+                    break to
+                }
+            };
+
+            rules.push((from.to_string(), to));
+        }
+    }
+
+    // Build the molecule
+    let mut molecule = vec!["e".to_string()];
+
+    for _ in 0..size.max(1) {
+        let positions: Vec<usize> = (0..molecule.len()).filter(|&i| rules.iter().any(|(f, _)| *f == molecule[i])).collect();
+
+        if positions.is_empty() {
+            break
+        }
+
+        let pos = *rng.choose(&positions);
+        let options: Vec<&Vec<String>> = rules.iter().filter(|(f, _)| *f == molecule[pos]).map(|(_, t)| t).collect();
+
+        let to = rng.choose(&options).to_vec();
+        molecule.splice(pos..=pos, to);
+    }
+
+    let mut text = String::new();
+
+    for (from, to) in &rules {
+        text += &format!("{} => {}\n", from, to.concat());
+    }
+
+    text += &format!("\n{}\n", molecule.concat());
+    text
+}
+
+fn random_replacement(rng: &mut Rng, from_e: bool) -> Vec<String> {
+    let elements: Vec<String> = (0..3).map(|_| rng.choose(&ELEMENTS).to_string()).collect();
+    let (a, b, c) = (elements[0].clone(), elements[1].clone(), elements[2].clone());
+
+    let shape = if from_e { 2 } else { rng.below(4) };
+
+    match shape {
+        0 => vec![a, "Rn".to_string(), b, "Ar".to_string()],
+        1 => vec![a, "Rn".to_string(), b, "Y".to_string(), c, "Ar".to_string()],
+        _ => vec![a, b]
+    }
+}
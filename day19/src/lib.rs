@@ -0,0 +1,267 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult, trace};
+use std::collections::{HashMap, HashSet, hash_map::DefaultHasher};
+use std::hash::{Hash, Hasher};
+
+pub type Replacements = HashMap<String, Vec<String>>;
+pub type Replacement = HashMap<String, String>;
+
+pub const DAY: Day = Day {
+    day: 19,
+    input: Some("input19.txt"),
+    parts: &[
+        Part { part: 1, desc: "Unique molecules", solve: part1 },
+        Part { part: 2, desc: "Minimum replacements", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let (fwd_replacements, _, molecule) = parse_sections(&input.numbered_sections())?;
+
+    Ok(unique_molecules(&molecule, &fwd_replacements).into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let (fwd_replacements, bwd_replacements, molecule) = parse_sections(&input.numbered_sections())?;
+
+    Ok(min_replacements(&molecule, &fwd_replacements, &bwd_replacements).into())
+}
+
+pub fn unique_molecules(molecule: &str, fwd_replacements: &Replacements) -> usize {
+    let mut hashes = HashSet::new();
+
+    let elements = split_molecule(molecule);
+
+    for i in 0..elements.len() {
+        if let Some(replacements) = fwd_replacements.get(&elements[i]) {
+            for r in replacements {
+                let new_molecule: String = elements.iter().enumerate().fold("".to_string(), |acc, (idx, e)| {
+                    if idx == i {
+                        acc + r
+                    } else {
+                        acc + e
+                    }
+                });
+
+                let mut hasher = DefaultHasher::new();
+                new_molecule.hash(&mut hasher);
+                let hash = hasher.finish();
+
+                hashes.insert(hash);
+            }
+        }
+    }
+
+    hashes.len()
+}
+
+/// Splits a molecule checked by parse_sections into its elements
+pub fn split_molecule(molecule: &str) -> Vec<String> {
+    let mut elements = Vec::new();
+    let chars: Vec<char> = molecule.chars().collect();
+
+    let mut pos = 0;
+    while pos < chars.len() {
+        assert!(chars[pos].is_uppercase());
+
+        pos += if pos + 1 < chars.len() && chars[pos + 1].is_lowercase() {
+            elements.push(chars[pos..pos + 2].iter().collect());
+            2
+        } else {
+            elements.push(chars[pos].to_string());
+            1
+        }
+    }
+
+    elements
+}
+
+pub fn min_replacements(molecule: &str, fwd_replacements: &Replacements, bwd_replacements: &Replacement) -> usize {
+    let mut min_rep = usize::MAX;
+
+    let mut terminals = Replacement::new();
+    let mut non_terminals = Replacement::new();
+
+    // Split bwd_replacements in to terminals and non-terminals
+    for (to, from) in bwd_replacements {
+        let split = split_molecule(to);
+        let mut terminal = false;
+
+        for s in split {
+            if fwd_replacements.get(&s).is_none() {
+                terminal = true;
+                break
+            }
+        }
+
+        if terminal {
+            terminals.insert(to.to_string(), from.clone());
+        } else {
+            non_terminals.insert(to.to_string(), from.clone());
+        }
+    }
+
+    part2_iter(molecule.to_string(), &terminals, &non_terminals, 0, &mut min_rep);
+
+    min_rep
+}
+
+fn part2_iter(molecule: String, terminals: &Replacement, non_terminals: &Replacement, mut no_rep: usize, min_rep: &mut usize) {
+    if no_rep >= *min_rep {
+        return
+    }
+
+    if molecule == "e" {
+        trace!("e {} at {}", molecule, no_rep);
+        *min_rep = no_rep;
+        return
+    }
+
+    // Replace terminals
+    let mut new_molecule1 = molecule.clone();
+    for (to, from) in terminals {
+        let trep = new_molecule1.match_indices(to).count();
+        if trep > 0 {
+            no_rep += trep;
+            new_molecule1 = new_molecule1.replace(to, from);
+        }
+    }
+    
+    // Iterate non-terminals
+    for (to, from) in non_terminals {
+        for (idx, _) in new_molecule1.match_indices(to) {
+            let mut new_molecule2 = "".to_string();
+            new_molecule2.push_str(&new_molecule1[0..idx]);
+            new_molecule2.push_str(from);
+            new_molecule2.push_str(&new_molecule1[idx + to.len()..]);
+
+            part2_iter(new_molecule2, terminals, non_terminals, no_rep + 1, min_rep);
+        }
+    }
+}
+
+pub fn parse_sections(sections: &[Vec<Line>]) -> Result<(Replacements, Replacement, String), ParseError> {
+    let mut fwd_replacements: Replacements = HashMap::new();
+    let mut bwd_replacements: Replacement = HashMap::new();
+
+    let rules = sections.first().ok_or_else(|| Line::new(1, "").line_error("replacement rules"))?;
+
+    for l in rules {
+        let (from, to) = l.text.split_once(" => ")
+            .ok_or_else(|| l.line_error("\"<from> => <to>\", or a blank line before the molecule"))?;
+
+        if from != "e" && split_molecule_checked(l, from)? != 1 {
+            return Err(l.error(from, "a single element or e"))
+        }
+
+        split_molecule_checked(l, to)?;
+
+        if let Some(rep) = fwd_replacements.get_mut(from) {
+            rep.push(to.to_string());
+        } else {
+            fwd_replacements.insert(from.to_string(), vec![to.to_string()]);
+        }
+
+        bwd_replacements.insert(to.to_string(), from.to_string());
+    }
+
+    // The molecule follows the blank line after the rules, on a line of its own
+    let molecule = match sections.get(1).map(|s| &s[..]) {
+        Some([l]) => {
+            split_molecule_checked(l, l.text)?;
+            l.text.to_string()
+        },
+        Some([_, extra, ..]) => return Err(extra.line_error("nothing after the molecule")),
+        _ => {
+            let end = rules.last().map_or(1, |l| l.number + 1);
+            return Err(Line::new(end, "").line_error("a blank line and then the molecule"))
+        }
+    };
+
+    if let Some(extra) = sections.get(2) {
+        return Err(extra[0].line_error("nothing after the molecule"))
+    }
+
+    Ok((fwd_replacements, bwd_replacements, molecule))
+}
+
+/// Checks that a slice of a line is a run of elements, each an upper case
+/// letter followed by an optional lower case letter, returning how many
+/// elements there are
+fn split_molecule_checked(l: &Line, elements: &str) -> Result<usize, ParseError> {
+    let mut count = 0;
+    let mut chars = elements.char_indices().peekable();
+
+    while let Some((pos, c)) = chars.next() {
+        if !c.is_ascii_uppercase() {
+            return Err(l.error(&elements[pos..pos + c.len_utf8()], "an element starting with an upper case letter"))
+        }
+
+        chars.next_if(|(_, c)| c.is_ascii_lowercase());
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(l.error(elements, "at least one element"))
+    }
+
+    Ok(count)
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("H => HO\nH => OH\nO => HH\n\nHOH\n");
+    let (fwd_replacements, _, molecule) = parse_sections(&input.numbered_sections()).unwrap();
+    assert!(molecule == "HOH" && unique_molecules(&molecule, &fwd_replacements) == 4);
+
+    // No blank line before the molecule
+    let input = Input::from("H => HO\nHOH\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 2 && err.text == "HOH" && err.expected.starts_with("\"<from> => <to>\""));
+
+    let input = Input::from("H => HO\nO -> HH\n\nHOH\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 2 && err.column == 1 && err.text == "O -> HH");
+
+    let input = Input::from("H => HO\nO => HH\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 3 && err.expected == "a blank line and then the molecule");
+
+    assert!(parse_sections(&Input::from("").numbered_sections()).unwrap_err().expected == "replacement rules");
+
+    // Elements must start with an upper case letter
+    let input = Input::from("H => HO\n\nhOH\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 3 && err.column == 1 && err.text == "h" && err.expected == "an element starting with an upper case letter");
+
+    let input = Input::from("e => HF\nCa => CaPB2\n\nHCa\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 2 && err.column == 11 && err.text == "2");
+
+    let input = Input::from("Ca => CaPB\nHO => H\n\nHCa\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 2 && err.column == 1 && err.text == "HO" && err.expected == "a single element or e");
+
+    let input = Input::from("H => \n\nHO\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 1 && err.column == 6 && err.text.is_empty() && err.expected == "at least one element");
+
+    // Only the molecule follows the rules
+    let input = Input::from("H => HO\n\nHOH\nHH\n");
+
+    let err = parse_sections(&input.numbered_sections()).unwrap_err();
+    assert!(err.line == 4 && err.text == "HH" && err.expected == "nothing after the molecule");
+
+    let input = Input::from("H => HO\n\nHOH\n\nHH\n");
+    assert!(parse_sections(&input.numbered_sections()).unwrap_err().line == 5);
+}
@@ -0,0 +1,10 @@
+//! Random present targets
+
+use aoc_lib::Rng;
+
+/// Generates a target of about size thousand presents
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let thousands = size.max(1) as i64;
+
+    format!("{}\n", rng.range(thousands * 1000..=thousands * 1000 + 999))
+}
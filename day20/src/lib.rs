@@ -0,0 +1,154 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult};
+
+pub const DAY: Day = Day {
+    day: 20,
+    input: Some("input20.txt"),
+    parts: &[
+        Part { part: 1, desc: "House", solve: part1 },
+        Part { part: 2, desc: "House with lazy elves", solve: part2 },
+    ],
+    params: &[
+        Param { name: "presents", desc: "Number of presents to look for" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let present_thresh: u64 = input.param_or_input("presents")?;
+
+    Ok(first_house(present_thresh).into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let present_thresh: u64 = input.param_or_input("presents")?;
+
+    Ok(first_house_lazy(present_thresh).into())
+}
+
+/// Finds the first house to get at least a number of presents when every elf
+/// delivers 10 presents to each multiple of their number
+pub fn first_house(present_thresh: u64) -> u64 {
+    let mut primes = Vec::with_capacity(1024);
+
+    for i in 1.. {
+        let sum = prime_fact_sum(i, &mut primes);
+
+        let presents = sum * 10;
+
+        if presents >= present_thresh {
+            return i
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn prime_fact_sum(num: u64, primes: &mut Vec<u64>) -> u64 {
+    let mut remain = num;
+    let mut pelem = 0;
+    let mut total: u64 = 1;
+
+    let mut add_power = |num: u64, power: u32| {
+        let power_sum = (num.pow(power + 1) - 1) / (num - 1);
+        total *= power_sum;
+    };
+
+    while pelem < primes.len() {
+        let prime = primes[pelem];
+
+        let mut power = 0;
+
+        while remain > 1 && remain.is_multiple_of(prime) {
+            remain /= prime;
+            power += 1;
+        }
+
+        if power > 0 {
+            add_power(prime, power);
+        }
+
+        if remain == 1 {
+            break;
+        }
+
+        pelem += 1;
+    }
+
+    if remain != 1 {
+        primes.push(remain);
+        add_power(remain, 1);
+    }
+
+    total
+}
+
+/// Finds the first house to get at least a number of presents when every elf
+/// delivers 11 presents to the first 50 multiples of their number
+pub fn first_house_lazy(present_thresh: u64) -> usize {
+    // House n gets at least 11 * n presents from elf n so the answer is within this range
+    let mut houses: Vec<u64> = vec![0; (present_thresh / 11) as usize + 2];
+    let mut min_house: usize = usize::MAX;
+    let mut got_answer = false;
+
+    for i in 1usize.. {
+        // Later elves can only deliver to later houses
+        if got_answer && i > min_house {
+            return min_house
+        }
+
+        let presents: u64 = 11 * i as u64;
+
+        for j in 1..=50 {
+            let elem = i * j as usize;
+
+            if elem >= houses.len() {
+                break
+            }
+
+            let new_presents = houses[elem] + presents;
+
+            if new_presents >= present_thresh {
+                if elem < min_house {
+                    got_answer = true;
+                    min_house = elem;
+                }
+            } else {
+                houses[elem] = new_presents;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+/// Finds the first house to get at least a number of presents by adding up
+/// every elf's deliveries to each house in turn. Much slower than the
+/// specialised searches, but simple enough to check them against
+pub fn first_house_sieve(present_thresh: u64, presents_per_elf: u64, houses_per_elf: Option<u64>) -> u64 {
+    // House n gets presents_per_elf * n from elf n, so the answer is at most this
+    let limit = present_thresh.div_ceil(presents_per_elf).max(1);
+    let mut houses = vec![0; limit as usize + 1];
+
+    for elf in 1..=limit {
+        let last = houses_per_elf.map_or(limit, |h| (elf * h).min(limit));
+
+        for house in (elf..=last).step_by(elf as usize) {
+            houses[house as usize] += presents_per_elf * elf;
+        }
+    }
+
+    (1..=limit).find(|&h| houses[h as usize] >= present_thresh).unwrap()
+}
+
+#[test]
+fn test_first_house_threshold() {
+    // Houses 1, 4 and 6 get 10, 70 and 120 presents, and a house getting
+    // exactly the number needed is the answer
+    assert!(first_house(10) == 1 && first_house(11) == 2);
+    assert!(first_house(70) == 4 && first_house(71) == 6);
+
+    assert!(first_house_lazy(11) == 1 && first_house_lazy(12) == 2);
+    assert!(first_house_lazy(77) == 4 && first_house_lazy(78) == 6);
+}
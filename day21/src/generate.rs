@@ -0,0 +1,8 @@
+//! Random boss statistics
+
+use aoc_lib::Rng;
+
+/// Generates a boss with size hit points, from 1 to 1000
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    format!("Hit Points: {}\nDamage: {}\nArmor: {}\n", size.clamp(1, 1000), rng.range(4..=9), rng.range(0..=3))
+}
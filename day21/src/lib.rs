@@ -0,0 +1,236 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, InputError, Param, Part, PartResult, Solution};
+
+pub const DAY: Day = Day {
+    day: 21,
+    input: Some("input21.txt"),
+    parts: &[
+        Part { part: 1, desc: "Minimum cost for player win", solve: part1 },
+        Part { part: 2, desc: "Maximum cost for boss win", solve: part2 },
+    ],
+    params: &[
+        Param { name: "hit_points", desc: "Boss hit points" },
+        Param { name: "damage", desc: "Boss damage" },
+        Param { name: "armor", desc: "Boss armor" },
+    ],
+    generate: generate::generate
+};
+
+pub struct KitProfile {
+    pub name: &'static str,
+    pub cost: u16,
+    pub damage: u16,
+    pub armor: u16
+}
+
+pub const WEAPONS: [KitProfile; 5] = [
+    KitProfile { name: "Dagger",     cost:   8, damage: 4, armor: 0 },
+    KitProfile { name: "Shortsword", cost:  10, damage: 5, armor: 0 },
+    KitProfile { name: "Warhammer",  cost:  25, damage: 6, armor: 0 },
+    KitProfile { name: "Longsword",  cost:  40, damage: 7, armor: 0 },
+    KitProfile { name: "Greataxe",   cost:  74, damage: 8, armor: 0 },
+];
+
+pub const ARMOR: [KitProfile; 5] = [
+    KitProfile { name: "Leather",    cost:  13, damage: 0, armor: 1 },
+    KitProfile { name: "Chainmail",  cost:  31, damage: 0, armor: 2 },
+    KitProfile { name: "Splintmail", cost:  53, damage: 0, armor: 3 },
+    KitProfile { name: "Bandedmail", cost:  75, damage: 0, armor: 4 },
+    KitProfile { name: "Platemail",  cost: 102, damage: 0, armor: 5 },
+];
+
+pub const RINGS: [KitProfile; 6] = [
+    KitProfile { name: "Damage +1",  cost:  25, damage: 1, armor: 0 },
+    KitProfile { name: "Damage +2",  cost:  50, damage: 2, armor: 0 },
+    KitProfile { name: "Damage +3",  cost: 100, damage: 3, armor: 0 },
+    KitProfile { name: "Defense +1", cost:  20, damage: 0, armor: 1 },
+    KitProfile { name: "Defense +2", cost:  40, damage: 0, armor: 2 },
+    KitProfile { name: "Defense +3", cost:  80, damage: 0, armor: 3 },
+];
+
+#[derive(Clone, Default)]
+pub struct PersonProfile {
+    pub hit_points: u16,
+    pub damage: u16,
+    pub armor: u16
+}
+
+pub fn load_boss_profile(input: &Input) -> std::result::Result<PersonProfile, InputError> {
+    Ok(PersonProfile {
+        hit_points: input.param_or_property("hit_points", "Hit Points")?,
+        damage: input.param_or_property("damage", "Damage")?,
+        armor: input.param_or_property("armor", "Armor")?
+    })
+}
+
+#[derive(Default)]
+struct Result<'a> {
+    cost: u16,
+    weapon: Option<&'a KitProfile>,
+    armor: Option<&'a KitProfile>,
+    ring1: Option<&'a KitProfile>,
+    ring2: Option<&'a KitProfile>,
+}
+
+impl<'a> Result<'a> {
+    fn equipment_list(&self) -> Vec<String> {
+        let mut equipment = Vec::new();
+
+        if let Some(weapon) = self.weapon { equipment.push(format!("Weapon: {}", weapon.name)) };
+        if let Some(armor) = self.armor { equipment.push(format!("Armor: {}", armor.name)) };
+        if let Some(ring1) = self.ring1 { equipment.push(format!("Ring 1: {}", ring1.name)) };
+        if let Some(ring2) = self.ring2 { equipment.push(format!("Ring 2: {}", ring2.name)) };
+        
+        equipment
+    }
+}
+
+type Callback<'a> = &'a mut dyn FnMut(&'static KitProfile, Option<&'static KitProfile>,
+    Option<&'static KitProfile>, Option<&'static KitProfile>);
+
+pub fn part1(input: &Input) -> PartResult {
+    let boss_profile = load_boss_profile(input)?;
+
+    let mut result: Result = Result {
+        cost: u16::MAX,
+        ..Default::default()
+    };
+
+    let mut part1_play = |weapon: &'static KitProfile, armor: Option<&'static KitProfile>,
+            ring1: Option<&'static KitProfile>, ring2: Option<&'static KitProfile>| {
+        let (mut player_profile, cost) = build_player_profile(weapon, armor, ring1, ring2);
+
+        if cost < result.cost {
+            // Play
+            if play(&mut player_profile, &boss_profile) {
+                // Player wins
+                result.cost = cost;
+                result.weapon = Some(weapon);
+                result.armor = armor;
+                result.ring1 = ring1;
+                result.ring2 = ring2;
+            }
+        }    
+    };
+
+    choose_weapon(&mut part1_play);
+
+    Ok(Solution::from(result.cost).with_detail("equipment", result.equipment_list()))
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let boss_profile = load_boss_profile(input)?;
+
+    let mut result: Result = Default::default();
+
+    let mut part2_play = |weapon: &'static KitProfile, armor: Option<&'static KitProfile>,
+            ring1: Option<&'static KitProfile>, ring2: Option<&'static KitProfile>| {
+        let (mut player_profile, cost) = build_player_profile(weapon, armor, ring1, ring2);
+
+        if cost > result.cost {    
+            // Play
+            if !play(&mut player_profile, &boss_profile) {
+                // Boss wins
+                result.cost = cost;
+                result.weapon = Some(weapon);
+                result.armor = armor;
+                result.ring1 = ring1;
+                result.ring2 = ring2;
+            }
+        }    
+    };
+
+    choose_weapon(&mut part2_play);
+
+    Ok(Solution::from(result.cost).with_detail("equipment", result.equipment_list()))
+}
+
+#[inline]
+fn build_player_profile(weapon: &'static KitProfile, armor: Option<&'static KitProfile>,
+        ring1: Option<&'static KitProfile>, ring2: Option<&'static KitProfile>)
+        -> (PersonProfile, u16) {
+    let mut player_profile: PersonProfile = Default::default();
+    let mut cost = 0;
+
+    // Set player hit points
+    player_profile.hit_points = 100;
+
+    // Add kit
+    let mut add = |e: &KitProfile| {
+        player_profile.damage += e.damage;
+        player_profile.armor += e.armor;
+        cost += e.cost;
+    };
+
+    add(weapon);
+    if let Some(armor) = armor { add(armor) };
+    if let Some(ring1) = ring1 { add(ring1) };
+    if let Some(ring2) = ring2 { add(ring2) };
+
+    (player_profile, cost)
+}
+
+fn choose_weapon(callback: Callback) {
+    // Choice of weapon
+    for w in WEAPONS.iter() {
+        choose_armor(callback, w);
+    }
+}
+
+fn choose_armor(callback: Callback, weapon: &'static KitProfile) {
+    // No armor
+    choose_rings(callback, weapon, None);
+
+    // Choice of armor
+    for a in ARMOR.iter() {
+        choose_rings(callback, weapon, Some(a));
+    }
+}
+
+fn choose_rings(callback: Callback, weapon: &'static KitProfile, armor: Option<&'static KitProfile>) {
+    // No rings
+    equip_and_play(callback, weapon, armor, None, None);
+
+    // One ring
+    for r in RINGS.iter() {
+        equip_and_play(callback, weapon, armor, Some(r), None);
+    }
+
+    // Two rings
+    for (i, ring1) in RINGS.iter().enumerate().take(RINGS.len() - 1) {
+        for ring2 in RINGS.iter().skip(i + 1) {
+            equip_and_play(callback, weapon, armor, Some(ring1), Some(ring2));
+        }
+    }
+}
+
+#[inline]
+fn equip_and_play(callback: Callback, weapon: &'static KitProfile, armor: Option<&'static KitProfile>,
+        ring1: Option<&'static KitProfile>, ring2: Option<&'static KitProfile>) {
+    callback(weapon, armor, ring1, ring2);
+}
+
+pub fn play(player_profile: &mut PersonProfile, boss_profile: &PersonProfile) -> bool {
+    let mut boss_profile = boss_profile.clone();
+
+    let player_damage = if boss_profile.armor >= player_profile.damage { 1 }
+    else { player_profile.damage - boss_profile.armor };
+
+    let boss_damage = if player_profile.armor >= boss_profile.damage { 1 }
+    else { boss_profile.damage - player_profile.armor };
+
+    loop {
+        // Player's turn
+        if player_damage >= boss_profile.hit_points {
+            break true
+        }
+        boss_profile.hit_points -= player_damage;
+
+        // Boss's turn
+        if boss_damage > player_profile.hit_points {
+            break false
+        }
+        player_profile.hit_points -= boss_damage;
+    }
+}
@@ -0,0 +1,8 @@
+//! Random boss statistics
+
+use aoc_lib::Rng;
+
+/// Generates a boss with size hit points, from 1 to 100
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    format!("Hit Points: {}\nDamage: {}\n", size.clamp(1, 100), rng.range(5..=10))
+}
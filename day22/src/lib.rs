@@ -1,6 +1,6 @@
-// Parameters from input file
-const BOSS_HIT_POINTS: u16 = 71;
-const BOSS_DAMAGE: u16 = 10;
+pub mod generate;
+
+use aoc_lib::{Day, Input, InputError, Param, Part, PartResult, Solution};
 
 const PLAYER_HIT_POINTS: u16 = 50;
 const PLAYER_MANA: u16 = 500;
@@ -24,6 +24,52 @@ const RECHARGE_COST: u16 = 229;
 const RECHARGE_MANA: u16 = 101;
 const RECHARGE_TIME: u8 = 5;
 
+pub const DAY: Day = Day {
+    day: 22,
+    input: Some("input22.txt"),
+    parts: &[
+        Part { part: 1, desc: "Minimum spend", solve: part1 },
+        Part { part: 2, desc: "Minimum spend on hard", solve: part2 },
+    ],
+    params: &[
+        Param { name: "hit_points", desc: "Boss hit points" },
+        Param { name: "damage", desc: "Boss damage" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let result = play(Difficulty::Normal, &load_boss(input)?);
+
+    solution(result)
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let result = play(Difficulty::Hard, &load_boss(input)?);
+
+    solution(result)
+}
+
+pub fn load_boss(input: &Input) -> std::result::Result<BossState, InputError> {
+    Ok(BossState {
+        hit_points: input.param_or_property("hit_points", "Hit Points")?,
+        damage: input.param_or_property("damage", "Damage")?
+    })
+}
+
+fn solution(result: Result) -> PartResult {
+    if result.player_wins == 0 {
+        return Err(format!("The boss wins all {} fights", result.boss_wins).into())
+    }
+
+    let spells: Vec<String> = result.min_spells.iter().map(|s| format!("{:?}", s)).collect();
+
+    Ok(Solution::from(result.min_spend)
+        .with_detail("min_spells", spells)
+        .with_detail("boss_wins", result.boss_wins)
+        .with_detail("player_wins", result.player_wins))
+}
+
 pub enum Difficulty {
     Normal,
     Hard
@@ -77,16 +123,9 @@ impl Default for PlayerState {
 }
 
 #[derive(Clone)]
-struct BossState {
-    hit_points: u16
-}
-
-impl Default for BossState {
-    fn default() -> Self {
-        Self {
-            hit_points: BOSS_HIT_POINTS
-        }
-    }
+pub struct BossState {
+    pub hit_points: u16,
+    pub damage: u16
 }
 
 pub struct Result {
@@ -107,9 +146,9 @@ impl Default for Result {
     }
 }
 
-pub fn play(difficulty: Difficulty) -> Result {
+pub fn play(difficulty: Difficulty, boss: &BossState) -> Result {
     let mut player: PlayerState = Default::default();
-    let mut boss: BossState = Default::default();
+    let mut boss = boss.clone();
     let mut result: Result = Default::default();
 
     player_turn(&difficulty, &mut result, &mut player, &mut boss);
@@ -244,7 +283,7 @@ fn boss_turn(difficulty: &Difficulty, result: &mut Result, player: &mut PlayerSt
         return
     };
 
-    if boss_attack(player) {
+    if boss_attack(player, boss) {
         // Boss has won
         boss_win(result);
         return
@@ -278,8 +317,8 @@ fn process_effects(player: &mut PlayerState, boss: &mut BossState) -> bool {
     false
 }
 
-fn boss_attack(player: &mut PlayerState) -> bool {
-    let damage = BOSS_DAMAGE - player.armor as u16;
+fn boss_attack(player: &mut PlayerState, boss: &BossState) -> bool {
+    let damage = boss.damage.saturating_sub(player.armor as u16).max(1);
 
     if player.hit_points <= damage {
         true
@@ -301,3 +340,12 @@ fn player_win(result: &mut Result, player: &PlayerState) {
 fn boss_win(result: &mut Result) {
     result.boss_wins += 1;
 }
+
+#[test]
+fn test_unbeatable_boss() {
+    let input = Input::from("Hit Points: 13\nDamage: 8\n");
+    assert!(part1(&input).unwrap().answer == 212u16.into());
+
+    let input = Input::from("Hit Points: 1000\nDamage: 50\n");
+    assert!(part1(&input).unwrap_err().to_string().starts_with("The boss wins all "));
+}
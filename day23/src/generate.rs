@@ -0,0 +1,55 @@
+//! Random programs
+
+use aoc_lib::Rng;
+
+/// Largest start value for the Collatz loop. Every sequence from below this
+/// stays within a 32 bit register
+const MAX_START: u32 = 100_000;
+
+/// Generates a program shaped like the puzzle input. Two blocks of size
+/// instructions each, up to 100, build a start value in register a, one for
+/// a = 0 and one for a = 1. A loop then counts the Collatz steps from that
+/// value into register b
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let len = size.clamp(1, 100);
+
+    let block0 = start_block(rng, 0, len);
+    let block1 = start_block(rng, 1, len);
+
+    let mut lines = vec![format!("jio a, +{}", block0.len() + 2)];
+    lines.extend(block0);
+    lines.push(format!("jmp +{}", block1.len() + 1));
+    lines.extend(block1);
+
+    lines.extend([
+        "jio a, +8", "inc b", "jie a, +4", "tpl a", "inc a", "jmp +2", "hlf a", "jmp -7"
+    ].iter().map(|l| l.to_string()));
+
+    lines.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+/// Returns increments and triples of register a which take it from a value
+/// to a start value of at least 1
+fn start_block(rng: &mut Rng, mut a: u32, len: usize) -> Vec<String> {
+    let mut block = Vec::with_capacity(len);
+
+    while block.len() < len {
+        if a > 0 && a * 3 < MAX_START && rng.chance(0.4) {
+            a *= 3;
+            block.push("tpl a".to_string());
+        } else if a + 1 < MAX_START {
+            a += 1;
+            block.push("inc a".to_string());
+        } else {
+            block.push("hlf a".to_string());
+            a /= 2;
+        }
+    }
+
+    // Make sure the loop starts from at least 1
+    if a == 0 {
+        block.push("inc a".to_string());
+    }
+
+    block
+}
@@ -0,0 +1,142 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Line, ParseError, Part, PartResult};
+
+pub const DAY: Day = Day {
+    day: 23,
+    input: Some("input23.txt"),
+    parts: &[
+        Part { part: 1, desc: "b register", solve: part1 },
+        Part { part: 2, desc: "b register starting with a=1", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let instructions = parse_instructions(&input.numbered_lines())?;
+
+    let state = run_program(&instructions, 0);
+
+    Ok(state.b.into())
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let instructions = parse_instructions(&input.numbered_lines())?;
+
+    let state = run_program(&instructions, 1);
+
+    Ok(state.b.into())
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Hlf(char),
+    Tpl(char),
+    Inc(char),
+    Jmp(i8),
+    Jie(char, i8),
+    Jio(char, i8)
+}
+
+#[derive(Default, Debug)]
+pub struct State {
+    pub a: u32,
+    pub b: u32,
+    pub pc: i16
+}
+
+impl State {
+    fn getreg(&self, reg: char) -> u32 {
+        match reg {
+            'a' => self.a,
+            'b' => self.b,
+            _ => panic!("Unrecognised register {}", reg)
+        }
+    }
+
+    fn setreg(&mut self, reg: char, value: u32) {
+        match reg {
+            'a' => self.a = value,
+            'b' => self.b = value,
+            _ => panic!("Unrecognised register {}", reg)
+        }
+    }
+}
+
+pub fn run_program(instructions: &[Instruction], a_reg: u32) -> State {
+    let mut state: State = State {
+        a: a_reg,
+        ..Default::default()
+    };
+
+    while state.pc >= 0 && state.pc < instructions.len() as i16 {
+        let instruction = &instructions[state.pc as usize];
+
+        // println!("{}: {:?}", state.pc, instruction);
+
+        match instruction {
+            Instruction::Hlf(reg) => state.setreg(*reg, state.getreg(*reg) / 2),
+            Instruction::Tpl(reg) => state.setreg(*reg, state.getreg(*reg) * 3),
+            Instruction::Inc(reg) => state.setreg(*reg, state.getreg(*reg) + 1),
+            Instruction::Jmp(offset) => state.pc += *offset as i16 - 1,
+            Instruction::Jie(reg, offset) => if state.getreg(*reg).is_multiple_of(2) { state.pc += *offset as i16 - 1 },
+            Instruction::Jio(reg, offset) => if state.getreg(*reg) == 1 { state.pc += *offset as i16 - 1 },
+        }
+
+        // println!("{:?}", state);
+
+        state.pc += 1;
+    }
+
+    state
+}
+
+pub fn parse_instructions(lines: &[Line]) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+
+    for l in lines {
+        let mut terms = l.text.split_whitespace();
+
+        let mut next = |expected: &str| terms.next().ok_or_else(|| l.error(&l.text[l.text.len()..], expected));
+
+        let reg = |term: &str| match term.trim_end_matches(',') {
+            "a" => Ok('a'),
+            "b" => Ok('b'),
+            _ => Err(l.error(term, "register a or b"))
+        };
+
+        let offset = |term: &str| l.parse::<i8>(term, "a jump offset");
+
+        let instruction = next("an instruction")?;
+
+        match instruction {
+            "hlf" => instructions.push(Instruction::Hlf(reg(next("a register")?)?)),
+            "tpl" => instructions.push(Instruction::Tpl(reg(next("a register")?)?)),
+            "inc" => instructions.push(Instruction::Inc(reg(next("a register")?)?)),
+            "jmp" => instructions.push(Instruction::Jmp(offset(next("a jump offset")?)?)),
+            "jie" => instructions.push(Instruction::Jie(reg(next("a register")?)?, offset(next("a jump offset")?)?)),
+            "jio" => instructions.push(Instruction::Jio(reg(next("a register")?)?, offset(next("a jump offset")?)?)),
+            _ => return Err(l.error(instruction, "hlf, tpl, inc, jmp, jie or jio"))
+        }
+
+        if let Some(extra) = terms.next() {
+            return Err(l.error(extra, "end of line"))
+        }
+    }
+
+    Ok(instructions)
+}
+
+#[test]
+fn test_parse_errors() {
+    let input = Input::from("inc a\n\njie c, +4\n");
+
+    let err = parse_instructions(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 3 && err.column == 5 && err.text == "c,");
+
+    let input = Input::from("jmp\n");
+
+    let err = parse_instructions(&input.numbered_lines()).unwrap_err();
+    assert!(err.line == 1 && err.column == 4 && err.expected == "a jump offset");
+}
@@ -0,0 +1,29 @@
+//! Random package weights
+
+use aoc_lib::Rng;
+
+/// Generates about size package weights, at least 24, which can be split into
+/// 3 or 4 groups of equal weight. The weights are built as 12 buckets of equal
+/// weight, so that 4 buckets make a third and 3 buckets make a quarter
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let per_bucket = (size / 12).clamp(2, 500) as i64;
+    let bucket = per_bucket * rng.range(8..=10);
+
+    let mut weights = Vec::new();
+
+    for _ in 0..12 {
+        let mut remaining = bucket;
+
+        for _ in 1..per_bucket {
+            let w = rng.range(1..=bucket / per_bucket);
+            weights.push(w);
+            remaining -= w;
+        }
+
+        weights.push(remaining);
+    }
+
+    weights.sort();
+
+    weights.iter().map(|w| format!("{}\n", w)).collect()
+}
@@ -0,0 +1,152 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Part, PartResult, Solution};
+use combi_lib::{BitSet, SubsetSum};
+
+pub const DAY: Day = Day {
+    day: 24,
+    input: Some("input24.txt"),
+    parts: &[
+        Part { part: 1, desc: "Minimum QE with 3 compartments", solve: part1 },
+        Part { part: 2, desc: "Minimum QE with 4 compartments", solve: part2 },
+    ],
+    params: &[],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let weights: Vec<u16> = input.parsed()?;
+
+    Ok(solution(calc_min_qe(&weights, 3)?))
+}
+
+pub fn part2(input: &Input) -> PartResult {
+    let weights: Vec<u16> = input.parsed()?;
+
+    Ok(solution(calc_min_qe(&weights, 4)?))
+}
+
+fn solution(packing: Packing) -> Solution {
+    Solution::from(packing.qe)
+        .with_detail("compartment_weight", packing.compartment_weight)
+        .with_detail("combinations", packing.combinations)
+        .with_detail("min_presents", packing.min_presents)
+        .with_detail("compartments", packing.compartments)
+}
+
+#[derive(Debug)]
+pub struct Packing {
+    pub qe: u64,
+    pub compartment_weight: u64,
+    pub combinations: usize,
+    pub min_presents: u32,
+    pub compartments: Vec<String>
+}
+
+/// Finds the packing with the fewest presents in the first compartment and
+/// then the lowest quantum entanglement. Fails if the presents can't be split
+/// into compartments of equal weight
+pub fn calc_min_qe(weights: &[u16], compartments: u8) -> Result<Packing, String> {
+    if weights.is_empty() || compartments < 2 {
+        return Err("at least one present and two compartments are needed".to_string())
+    }
+
+    let packages = weights.len();
+    let values: Vec<u64> = weights.iter().map(|&w| w as u64).collect();
+    let total_weight = values.iter().sum::<u64>();
+
+    if total_weight % compartments as u64 != 0 {
+        return Err(format!("total weight {} doesn't divide into {} compartments", total_weight, compartments))
+    }
+
+    let weight_per_compartment = total_weight / compartments as u64;
+    let no_split = || format!("no way to split the presents into {} compartments of weight {}", compartments, weight_per_compartment);
+
+    // Find all combinations of weights which add up to the target weight
+    let combinations = SubsetSum::new(&values, weight_per_compartment).subsets();
+
+    // Get minimum number of presents in a combination
+    let min_presents = combinations.iter().map(|c| c.len()).min().ok_or_else(no_split)?;
+
+    // Filter the array by minimum number of presents
+    let mut min_combinations: Vec<(&BitSet, u64)> = combinations.iter().filter_map(|c| {
+        if c.len() == min_presents {
+            Some((c, c.select(weights).map(|&w| w as u64).product()))
+        } else {
+            None
+        }
+    }).collect();
+
+    // Sort the array by QE
+    min_combinations.sort_by(|&(_, qe1), &(_, qe2)| {
+        qe1.cmp(&qe2)
+    });
+
+    for (set, qe) in min_combinations {
+        let mut other_comp = Vec::new();
+
+        if check_combination(&combinations, set, compartments, packages, &mut other_comp) {
+            let mut compartment_list = vec![set.join(weights, "+")];
+            compartment_list.extend(other_comp.iter().map(|other| other.join(weights, "+")));
+
+            return Ok(Packing {
+                qe,
+                compartment_weight: weight_per_compartment,
+                combinations: combinations.len(),
+                min_presents: min_presents as u32,
+                compartments: compartment_list
+            })
+        }
+    }
+
+    Err(no_split())
+}
+
+fn check_combination<'a>(combinations: &'a [BitSet], set: &BitSet, compartments: u8, packages: usize,
+        other_comp: &mut Vec<&'a BitSet>) -> bool {
+    let full = BitSet::full(packages);
+
+    check_combination_iter(combinations, set, &full, other_comp, 0, compartments as usize - 2)
+}
+
+fn check_combination_iter<'a>(combinations: &'a [BitSet], set: &BitSet, full: &BitSet, other_comp: &mut Vec<&'a BitSet>,
+        start: usize, depth: usize) -> bool {
+
+    for i in start..combinations.len().saturating_sub(depth) {
+        let filled = &combinations[i];
+
+        // Check for overlaps
+        if !set.is_disjoint(filled) {
+            continue
+        };
+
+        // This one fits
+        let cur = set.union(filled);
+
+        if depth == 0 {
+            if cur == *full {
+                other_comp.push(filled);
+                return true
+            }
+        } else if check_combination_iter(combinations, &cur, full, other_comp, i + 1, depth - 1) {
+            other_comp.push(filled);
+            return true
+        }
+    }
+
+    false
+}
+
+#[test]
+fn test_calc_min_qe() {
+    let weights = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+
+    let packing = calc_min_qe(&weights, 3).unwrap();
+    assert!(packing.qe == 99 && packing.compartment_weight == 20 && packing.min_presents == 2);
+    assert!(calc_min_qe(&weights, 4).unwrap().qe == 44);
+
+    assert!(calc_min_qe(&weights, 7).unwrap_err() == "total weight 60 doesn't divide into 7 compartments");
+    assert!(calc_min_qe(&[1, 1, 4], 3).unwrap_err() == "no way to split the presents into 3 compartments of weight 2");
+    assert!(calc_min_qe(&[1, 5], 2).is_err());
+    assert!(calc_min_qe(&[], 3).is_err());
+}
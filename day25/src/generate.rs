@@ -0,0 +1,11 @@
+//! Random code grid positions
+
+use aoc_lib::Rng;
+
+/// Generates a row and column from 1 to size
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let max = size.max(1) as i64;
+
+    format!("To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.\n",
+        rng.range(1..=max), rng.range(1..=max))
+}
@@ -0,0 +1,78 @@
+pub mod generate;
+
+use aoc_lib::{Day, Input, Param, Part, PartResult};
+use regex::Regex;
+
+pub const DAY: Day = Day {
+    day: 25,
+    input: Some("input25.txt"),
+    parts: &[
+        Part { part: 1, desc: "Code", solve: part1 },
+    ],
+    params: &[
+        Param { name: "row", desc: "Code row" },
+        Param { name: "column", desc: "Code column" },
+    ],
+    generate: generate::generate
+};
+
+pub fn part1(input: &Input) -> PartResult {
+    let (row, col) = load_coord(input)?;
+
+    Ok(num_at_coord(col, row).into())
+}
+
+fn load_coord(input: &Input) -> Result<(u64, u64), Box<dyn std::error::Error>> {
+    let re = Regex::new(r"row (\d+), column (\d+)").unwrap();
+
+    let (row, col) = match re.captures(input.text()) {
+        Some(caps) => (Some(caps[1].parse()?), Some(caps[2].parse()?)),
+        None => (None, None)
+    };
+
+    let row = input.param("row")?.or(row).ok_or("Row not found in input")?;
+    let col = input.param("column")?.or(col).ok_or("Column not found in input")?;
+
+    if row == 0 || col == 0 {
+        return Err("Row and column must be at least 1".into())
+    }
+
+    Ok((row, col))
+}
+
+pub fn coord_to_seq(x: u64, y: u64) -> u64 {
+    // n(n+1)/2
+    let xy_sum = x + y;
+    let triangle_num = (xy_sum * (xy_sum - 1)) / 2;
+
+    triangle_num - (y - 1)
+}
+
+pub fn num_at_coord(x: u64, y: u64) -> u64{
+    let num_seq = coord_to_seq(x, y);
+
+    let mut num: u64 = 20151125;
+    for _ in 1..num_seq {
+        num = (num * 252533) % 33554393;
+    }
+
+    num
+}
+
+#[test]
+fn test_coord_to_seq() {
+    assert!(coord_to_seq(1, 1) == 1);
+    assert!(coord_to_seq(2, 2) == 5);
+    assert!(coord_to_seq(4, 3) == 19);
+    assert!(coord_to_seq(3, 4) == 18);
+    assert!(coord_to_seq(2, 4) == 12);
+}
+
+#[test]
+fn test_num_at_coord() {
+    assert!(num_at_coord(1, 1) == 20151125);
+    assert!(num_at_coord(2, 2) == 21629792);
+    assert!(num_at_coord(4, 3) == 7981243);
+    assert!(num_at_coord(3, 4) == 21345942);
+    assert!(num_at_coord(2, 4) == 32451966);
+}
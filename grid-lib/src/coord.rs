@@ -0,0 +1,122 @@
+//! Grid coordinates and directions
+
+use std::{fmt, ops::{Add, AddAssign, Sub}};
+
+/// A grid coordinate. y increases downwards
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub x: i32,
+    pub y: i32
+}
+
+impl Coord {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the coordinates adjacent to this one
+    pub fn neighbours(self, adjacency: Adjacency) -> impl Iterator<Item = Coord> {
+        adjacency.offsets().iter().map(move |&offset| self + offset)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Coord) -> Coord {
+        Coord::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Coord) -> Coord {
+        Coord::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Which cells count as neighbours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    /// Up, down, left and right
+    Four,
+    /// Including diagonals
+    Eight
+}
+
+const FOUR_OFFSETS: [Coord; 4] = [
+    Coord::new(0, -1), Coord::new(-1, 0), Coord::new(1, 0), Coord::new(0, 1)
+];
+
+const EIGHT_OFFSETS: [Coord; 8] = [
+    Coord::new(-1, -1), Coord::new(0, -1), Coord::new(1, -1),
+    Coord::new(-1, 0), Coord::new(1, 0),
+    Coord::new(-1, 1), Coord::new(0, 1), Coord::new(1, 1)
+];
+
+impl Adjacency {
+    /// Returns the offsets of the neighbours of a cell
+    pub fn offsets(self) -> &'static [Coord] {
+        match self {
+            Adjacency::Four => &FOUR_OFFSETS,
+            Adjacency::Eight => &EIGHT_OFFSETS
+        }
+    }
+}
+
+/// A direction of movement on the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+impl Dir {
+    /// Parses an arrow character (^, v, < or >)
+    pub fn from_arrow(c: char) -> Option<Dir> {
+        match c {
+            '^' => Some(Dir::Up),
+            'v' => Some(Dir::Down),
+            '<' => Some(Dir::Left),
+            '>' => Some(Dir::Right),
+            _ => None
+        }
+    }
+
+    /// Returns the coordinate offset of a single step in this direction
+    pub fn offset(self) -> Coord {
+        match self {
+            Dir::Up => Coord::new(0, -1),
+            Dir::Down => Coord::new(0, 1),
+            Dir::Left => Coord::new(-1, 0),
+            Dir::Right => Coord::new(1, 0)
+        }
+    }
+}
+
+#[test]
+fn test_neighbours() {
+    let coord = Coord::new(5, 5);
+
+    assert!(coord.neighbours(Adjacency::Four).count() == 4);
+    assert!(coord.neighbours(Adjacency::Eight).count() == 8);
+    assert!(coord.neighbours(Adjacency::Eight).all(|c| c != coord && (c.x - 5).abs() <= 1 && (c.y - 5).abs() <= 1));
+
+    assert!(coord + Dir::from_arrow('<').unwrap().offset() == Coord::new(4, 5));
+}
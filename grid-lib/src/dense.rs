@@ -0,0 +1,276 @@
+//! Dense grids storing every cell of a fixed size rectangle
+
+use crate::{Adjacency, Coord, Rect};
+
+/// How neighbours are found at the edges of a dense grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Cells outside the grid are not neighbours
+    Bounded,
+    /// The grid wraps around at the edges
+    Toroidal
+}
+
+/// A grid with a cell for every coordinate from (0, 0) to (width - 1, height - 1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T: Clone> DenseGrid<T> {
+    /// Builds a grid with every cell set to a value
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height]
+        }
+    }
+
+    /// Sets every cell in a rectangle, clipped to the grid, to a value
+    pub fn fill_rect(&mut self, rect: &Rect, value: T) {
+        self.update_rect(rect, |cell| *cell = value.clone());
+    }
+}
+
+impl<T> DenseGrid<T> {
+    /// Builds a grid from rows of cells, which must all be the same length
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Result<Self, String> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let len = cells.len() - before;
+
+            match width {
+                None => width = Some(len),
+                Some(w) if w != len => return Err(format!("Row {} has {} cells, expected {}", height + 1, len, w)),
+                _ => ()
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells
+        })
+    }
+
+    /// Parses a grid from lines of text, one cell per character
+    pub fn parse<F>(lines: &[&str], mut cell: F) -> Result<Self, String>
+    where
+        F: FnMut(char) -> Option<T>
+    {
+        let rows: Result<Vec<Vec<T>>, String> = lines.iter().enumerate().map(|(y, l)| {
+            l.chars().enumerate().map(|(x, c)| {
+                cell(c).ok_or_else(|| format!("Invalid character '{}' at {},{}", c, x, y))
+            }).collect()
+        }).collect();
+
+        Self::from_rows(rows?)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the rectangle covered by the grid, if it is not empty
+    pub fn rect(&self) -> Option<Rect> {
+        if self.width == 0 || self.height == 0 {
+            None
+        } else {
+            Some(Rect::from_size(Coord::new(0, 0), self.width as u32, self.height as u32))
+        }
+    }
+
+    pub fn contains(&self, c: Coord) -> bool {
+        c.x >= 0 && c.y >= 0 && (c.x as usize) < self.width && (c.y as usize) < self.height
+    }
+
+    fn index(&self, c: Coord) -> Option<usize> {
+        if self.contains(c) {
+            Some(c.y as usize * self.width + c.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        self.index(c).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        self.index(c).map(move |i| &mut self.cells[i])
+    }
+
+    /// Sets a cell, returning false if the coordinate is outside the grid
+    pub fn set(&mut self, c: Coord, value: T) -> bool {
+        match self.get_mut(c) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false
+        }
+    }
+
+    /// Iterates the cells row by row with their coordinates
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+
+        self.cells.iter().enumerate().map(move |(i, cell)| {
+            (Coord::new((i % width) as i32, (i / width) as i32), cell)
+        })
+    }
+
+    /// Iterates the cell values row by row
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Returns a row of cells
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Counts the cells matching a predicate
+    pub fn count<F: Fn(&T) -> bool>(&self, pred: F) -> usize {
+        self.cells.iter().filter(|c| pred(c)).count()
+    }
+
+    /// Builds a grid of the same size by mapping each cell with its coordinate
+    pub fn map<U, F: FnMut(Coord, &T) -> U>(&self, mut f: F) -> DenseGrid<U> {
+        DenseGrid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(c, cell)| f(c, cell)).collect()
+        }
+    }
+
+    /// Updates every cell in a rectangle, clipped to the grid
+    pub fn update_rect<F: FnMut(&mut T)>(&mut self, rect: &Rect, mut f: F) {
+        let clipped = match self.rect().and_then(|r| r.intersect(rect)) {
+            Some(clipped) => clipped,
+            None => return
+        };
+
+        for y in clipped.min.y..=clipped.max.y {
+            let start = y as usize * self.width;
+
+            for cell in &mut self.cells[start + clipped.min.x as usize..=start + clipped.max.x as usize] {
+                f(cell);
+            }
+        }
+    }
+
+    /// Returns the coordinates of the neighbours of a cell
+    pub fn neighbours(&self, c: Coord, adjacency: Adjacency, topology: Topology) -> impl Iterator<Item = Coord> + '_ {
+        let (width, height) = (self.width as i32, self.height as i32);
+        let offsets = adjacency.offsets();
+        let wrap = move |offset: Coord| {
+            let n = c + offset;
+            Coord::new(n.x.rem_euclid(width), n.y.rem_euclid(height))
+        };
+
+        offsets.iter().enumerate().filter_map(move |(i, &offset)| match topology {
+            Topology::Bounded => Some(c + offset).filter(|&n| self.contains(n)),
+            Topology::Toroidal => {
+                let wrapped = wrap(offset);
+
+                // On grids less than 3 cells across, wrapping reaches the
+                // same cell from both sides, or the cell itself
+                if wrapped != c && !offsets[..i].iter().any(|&earlier| wrap(earlier) == wrapped) {
+                    Some(wrapped)
+                } else {
+                    None
+                }
+            }
+        })
+    }
+
+    /// Renders the grid as text, one character per cell
+    pub fn render_text<F: Fn(&T) -> char>(&self, f: F) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+
+        for y in 0..self.height {
+            text.extend(self.row(y).iter().map(&f));
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Renders the grid as a plain (P1) PBM bitmap, with set pixels black
+    pub fn render_pbm<F: Fn(&T) -> bool>(&self, f: F) -> String {
+        let mut pbm = format!("P1\n{} {}\n", self.width, self.height);
+
+        for y in 0..self.height {
+            let row: Vec<&str> = self.row(y).iter().map(|c| if f(c) { "1" } else { "0" }).collect();
+
+            pbm.push_str(&row.join(" "));
+            pbm.push('\n');
+        }
+
+        pbm
+    }
+}
+
+#[test]
+fn test_dense_grid() {
+    let mut grid = DenseGrid::parse(&[".#.", "##."], |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    }).unwrap();
+
+    assert!(grid.width() == 3 && grid.height() == 2);
+    assert!(grid.count(|c| *c) == 3);
+    assert!(grid.get(Coord::new(1, 0)) == Some(&true));
+    assert!(grid.get(Coord::new(3, 0)).is_none());
+
+    grid.fill_rect(&Rect::new(Coord::new(2, 0), Coord::new(5, 5)), true);
+    assert!(grid.render_text(|c| if *c { '#' } else { '.' }) == ".##\n###\n");
+    assert!(grid.render_pbm(|c| *c) == "P1\n3 2\n0 1 1\n1 1 1\n");
+
+    assert!(DenseGrid::parse(&["#x"], |c| if c == '#' { Some(true) } else { None }).is_err());
+    assert!(DenseGrid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+}
+
+#[test]
+fn test_dense_neighbours() {
+    let grid = DenseGrid::new(4, 3, 0);
+
+    assert!(grid.neighbours(Coord::new(0, 0), Adjacency::Eight, Topology::Bounded).count() == 3);
+    assert!(grid.neighbours(Coord::new(1, 1), Adjacency::Eight, Topology::Bounded).count() == 8);
+    assert!(grid.neighbours(Coord::new(0, 0), Adjacency::Four, Topology::Bounded).count() == 2);
+
+    let mut wrapped: Vec<Coord> = grid.neighbours(Coord::new(0, 0), Adjacency::Four, Topology::Toroidal).collect();
+    wrapped.sort();
+    assert!(wrapped == vec![Coord::new(0, 1), Coord::new(0, 2), Coord::new(1, 0), Coord::new(3, 0)]);
+
+    // Each cell of a 2 wide grid appears once, however many ways it is reached
+    let narrow = DenseGrid::new(2, 5, 0);
+
+    let mut wrapped: Vec<Coord> = narrow.neighbours(Coord::new(0, 0), Adjacency::Eight, Topology::Toroidal).collect();
+    wrapped.sort();
+    assert!(wrapped == vec![Coord::new(0, 1), Coord::new(0, 4), Coord::new(1, 0), Coord::new(1, 1), Coord::new(1, 4)]);
+
+    let flat = DenseGrid::new(6, 2, 0);
+    assert!(flat.neighbours(Coord::new(3, 1), Adjacency::Eight, Topology::Toroidal).count() == 5);
+    assert!(flat.neighbours(Coord::new(3, 1), Adjacency::Four, Topology::Toroidal).count() == 3);
+
+    let tiny = DenseGrid::new(2, 2, 0);
+    assert!(tiny.neighbours(Coord::new(1, 1), Adjacency::Eight, Topology::Toroidal).count() == 3);
+    assert!(DenseGrid::new(1, 1, 0).neighbours(Coord::new(0, 0), Adjacency::Eight, Topology::Toroidal).count() == 0);
+}
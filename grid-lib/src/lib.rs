@@ -0,0 +1,13 @@
+//! Two dimensional grids with typed coordinates
+
+pub mod coord;
+pub mod dense;
+#[cfg(feature = "png")]
+pub mod png;
+pub mod rect;
+pub mod sparse;
+
+pub use coord::{Adjacency, Coord, Dir};
+pub use dense::{DenseGrid, Topology};
+pub use rect::Rect;
+pub use sparse::SparseGrid;
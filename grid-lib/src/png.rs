@@ -0,0 +1,67 @@
+//! Minimal PNG encoding of grayscale images
+
+use flate2::{write::ZlibEncoder, Compression};
+use std::io::Write;
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Encodes 8 bit grayscale pixels, stored row by row, as a PNG image
+pub fn encode_gray(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    assert!(pixels.len() == width as usize * height as usize, "Wrong number of pixels");
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // 8 bits per pixel, grayscale, deflate, no filtering, not interlaced
+    header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+    // Each row starts with its filter type, which is always none
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+
+    if width > 0 {
+        for row in pixels.chunks(width as usize) {
+            encoder.write_all(&[0]).unwrap();
+            encoder.write_all(row).unwrap();
+        }
+    }
+
+    let data = encoder.finish().unwrap();
+
+    let mut png = SIGNATURE.to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &data);
+    write_chunk(&mut png, b"IEND", &[]);
+
+    png
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    png.extend_from_slice(&crc.finalize().to_be_bytes());
+}
+
+#[test]
+fn test_encode_gray() {
+    use flate2::read::ZlibDecoder;
+    use std::{convert::TryInto, io::Read};
+
+    let png = encode_gray(2, 2, &[0, 64, 128, 255]);
+
+    assert!(png.starts_with(SIGNATURE));
+    assert!(png[12..16] == *b"IHDR" && png[16..24] == [0, 0, 0, 2, 0, 0, 0, 2]);
+    assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+
+    // The image data follows the 25 byte header chunk
+    let len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+    assert!(png[37..41] == *b"IDAT");
+
+    let mut rows = Vec::new();
+    ZlibDecoder::new(&png[41..41 + len]).read_to_end(&mut rows).unwrap();
+    assert!(rows == [0, 0, 64, 0, 128, 255]);
+}
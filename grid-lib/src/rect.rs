@@ -0,0 +1,101 @@
+//! Rectangular grid regions
+
+use crate::Coord;
+use std::cmp::{max, min};
+
+/// A rectangle of grid cells with inclusive corners
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Coord,
+    pub max: Coord
+}
+
+impl Rect {
+    /// Builds the rectangle covering two corner cells in any order
+    pub fn new(a: Coord, b: Coord) -> Self {
+        Self {
+            min: Coord::new(min(a.x, b.x), min(a.y, b.y)),
+            max: Coord::new(max(a.x, b.x), max(a.y, b.y))
+        }
+    }
+
+    /// Builds a rectangle from its top left cell and size, which must not be zero
+    pub fn from_size(origin: Coord, width: u32, height: u32) -> Self {
+        assert!(width > 0 && height > 0, "Empty rectangle");
+
+        Self {
+            min: origin,
+            max: Coord::new(origin.x + width as i32 - 1, origin.y + height as i32 - 1)
+        }
+    }
+
+    /// Returns the smallest rectangle containing all of the coordinates, if any
+    pub fn bounding<I: IntoIterator<Item = Coord>>(coords: I) -> Option<Self> {
+        coords.into_iter().fold(None, |rect: Option<Rect>, c| match rect {
+            None => Some(Rect::new(c, c)),
+            Some(r) => Some(r.extend(c))
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        (self.max.x - self.min.x + 1) as u32
+    }
+
+    pub fn height(&self) -> u32 {
+        (self.max.y - self.min.y + 1) as u32
+    }
+
+    /// Returns the number of cells in the rectangle
+    pub fn area(&self) -> u64 {
+        self.width() as u64 * self.height() as u64
+    }
+
+    pub fn contains(&self, c: Coord) -> bool {
+        c.x >= self.min.x && c.x <= self.max.x && c.y >= self.min.y && c.y <= self.max.y
+    }
+
+    /// Returns the rectangle grown to include a coordinate
+    pub fn extend(&self, c: Coord) -> Self {
+        Self {
+            min: Coord::new(min(self.min.x, c.x), min(self.min.y, c.y)),
+            max: Coord::new(max(self.max.x, c.x), max(self.max.y, c.y))
+        }
+    }
+
+    /// Returns the overlap of two rectangles, if any
+    pub fn intersect(&self, other: &Rect) -> Option<Self> {
+        let min_c = Coord::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y));
+        let max_c = Coord::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y));
+
+        if min_c.x <= max_c.x && min_c.y <= max_c.y {
+            Some(Self { min: min_c, max: max_c })
+        } else {
+            None
+        }
+    }
+
+    /// Iterates the coordinates in the rectangle row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let rect = *self;
+
+        (rect.min.y..=rect.max.y).flat_map(move |y| (rect.min.x..=rect.max.x).map(move |x| Coord::new(x, y)))
+    }
+}
+
+#[test]
+fn test_rect() {
+    let rect = Rect::new(Coord::new(3, 4), Coord::new(1, 2));
+
+    assert!(rect.min == Coord::new(1, 2) && rect.max == Coord::new(3, 4));
+    assert!(rect.width() == 3 && rect.height() == 3 && rect.area() == 9);
+    assert!(rect.coords().count() == 9);
+    assert!(rect.contains(Coord::new(2, 3)) && !rect.contains(Coord::new(0, 3)));
+
+    let other = Rect::from_size(Coord::new(3, 0), 5, 3);
+    assert!(rect.intersect(&other) == Some(Rect::new(Coord::new(3, 2), Coord::new(3, 2))));
+    assert!(rect.intersect(&Rect::new(Coord::new(10, 10), Coord::new(11, 11))).is_none());
+
+    let bounds = Rect::bounding(vec![Coord::new(0, 0), Coord::new(-2, 5), Coord::new(1, -1)]);
+    assert!(bounds == Some(Rect::new(Coord::new(-2, -1), Coord::new(1, 5))));
+    assert!(Rect::bounding(Vec::new()).is_none());
+}
@@ -0,0 +1,157 @@
+//! Sparse grids storing only the cells which have been set
+
+use crate::{Adjacency, Coord, Rect};
+use std::collections::{hash_map, HashMap};
+
+/// An unbounded grid holding values only for cells which have been set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new()
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        self.cells.get(&c)
+    }
+
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&c)
+    }
+
+    /// Sets a cell, returning the previous value
+    pub fn set(&mut self, c: Coord, value: T) -> Option<T> {
+        self.cells.insert(c, value)
+    }
+
+    /// Returns a cell, setting it to the default value first if it is not set
+    pub fn get_or_default(&mut self, c: Coord) -> &mut T
+    where
+        T: Default
+    {
+        self.cells.entry(c).or_default()
+    }
+
+    pub fn remove(&mut self, c: Coord) -> Option<T> {
+        self.cells.remove(&c)
+    }
+
+    /// Returns the number of cells which have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterates the set cells in no particular order
+    pub fn iter(&self) -> hash_map::Iter<'_, Coord, T> {
+        self.cells.iter()
+    }
+
+    /// Returns the smallest rectangle containing every set cell, if any
+    pub fn bounds(&self) -> Option<Rect> {
+        Rect::bounding(self.cells.keys().copied())
+    }
+
+    /// Returns the set cells adjacent to a cell with their values
+    pub fn neighbours(&self, c: Coord, adjacency: Adjacency) -> impl Iterator<Item = (Coord, &T)> {
+        c.neighbours(adjacency).filter_map(move |n| self.cells.get(&n).map(|v| (n, v)))
+    }
+
+    /// Renders the cells within a rectangle as text, using a character for
+    /// cells which are not set
+    pub fn render_text<F: Fn(&T) -> char>(&self, rect: &Rect, unset: char, f: F) -> String {
+        let mut text = String::new();
+
+        for y in rect.min.y..=rect.max.y {
+            for x in rect.min.x..=rect.max.x {
+                text.push(self.get(Coord::new(x, y)).map(&f).unwrap_or(unset));
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Renders the cells within a rectangle as a plain (P1) PBM bitmap. Cells
+    /// which are not set are white
+    pub fn render_pbm<F: Fn(&T) -> bool>(&self, rect: &Rect, f: F) -> String {
+        let mut pbm = format!("P1\n{} {}\n", rect.width(), rect.height());
+
+        for y in rect.min.y..=rect.max.y {
+            let row: Vec<&str> = (rect.min.x..=rect.max.x).map(|x| {
+                match self.get(Coord::new(x, y)) {
+                    Some(c) if f(c) => "1",
+                    _ => "0"
+                }
+            }).collect();
+
+            pbm.push_str(&row.join(" "));
+            pbm.push('\n');
+        }
+
+        pbm
+    }
+
+    /// Renders the cells within a rectangle as a plain (P2) PGM grayscale
+    /// image with a maximum value of 255. Cells which are not set are black
+    pub fn render_pgm<F: Fn(&T) -> u8>(&self, rect: &Rect, f: F) -> String {
+        let mut pgm = format!("P2\n{} {}\n255\n", rect.width(), rect.height());
+
+        for row in self.gray_pixels(rect, f).chunks(rect.width() as usize) {
+            let row: Vec<String> = row.iter().map(|p| p.to_string()).collect();
+
+            pgm.push_str(&row.join(" "));
+            pgm.push('\n');
+        }
+
+        pgm
+    }
+
+    /// Renders the cells within a rectangle as a grayscale PNG image. Cells
+    /// which are not set are black
+    #[cfg(feature = "png")]
+    pub fn render_png<F: Fn(&T) -> u8>(&self, rect: &Rect, f: F) -> Vec<u8> {
+        crate::png::encode_gray(rect.width(), rect.height(), &self.gray_pixels(rect, f))
+    }
+
+    fn gray_pixels<F: Fn(&T) -> u8>(&self, rect: &Rect, f: F) -> Vec<u8> {
+        rect.coords().map(|c| self.get(c).map(&f).unwrap_or(0)).collect()
+    }
+}
+
+#[test]
+fn test_sparse_grid() {
+    let mut grid: SparseGrid<u32> = SparseGrid::new();
+
+    *grid.get_or_default(Coord::new(0, 0)) += 1;
+    *grid.get_or_default(Coord::new(-1, 0)) += 1;
+    *grid.get_or_default(Coord::new(0, 0)) += 1;
+
+    assert!(grid.len() == 2);
+    assert!(grid.get(Coord::new(0, 0)) == Some(&2));
+    assert!(grid.neighbours(Coord::new(-1, -1), Adjacency::Eight).count() == 2);
+    assert!(grid.neighbours(Coord::new(-1, -1), Adjacency::Four).count() == 1);
+
+    let bounds = grid.bounds().unwrap();
+    assert!(bounds == Rect::new(Coord::new(-1, 0), Coord::new(0, 0)));
+    assert!(grid.render_text(&bounds, '.', |v| char::from_digit(*v, 10).unwrap()) == "12\n");
+    assert!(grid.render_pbm(&bounds, |v| *v > 1) == "P1\n2 1\n0 1\n");
+
+    let rect = Rect::new(Coord::new(-1, -1), Coord::new(0, 0));
+    assert!(grid.render_pgm(&rect, |v| *v as u8 * 100) == "P2\n2 2\n255\n0 0\n100 200\n");
+}
@@ -0,0 +1,61 @@
+//! Complete or partial graphs with weighted edges
+
+/// A graph with numbered nodes and an optional weight for each directed edge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W> {
+    nodes: usize,
+    weights: Vec<Option<W>>
+}
+
+impl<W: Copy + PartialEq> Graph<W> {
+    /// Builds a graph with no edges
+    pub fn new(nodes: usize) -> Self {
+        Self {
+            nodes,
+            weights: vec![None; nodes * nodes]
+        }
+    }
+
+    /// Returns the number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes == 0
+    }
+
+    /// Returns the weight of the edge from one node to another, if any
+    pub fn get(&self, from: usize, to: usize) -> Option<W> {
+        self.weights[from * self.nodes + to]
+    }
+
+    /// Sets the weight of the edge from one node to another
+    pub fn set(&mut self, from: usize, to: usize, weight: W) {
+        self.weights[from * self.nodes + to] = Some(weight);
+    }
+
+    /// Sets the weight of the edges in both directions between two nodes
+    pub fn set_both(&mut self, a: usize, b: usize, weight: W) {
+        self.set(a, b, weight);
+        self.set(b, a, weight);
+    }
+
+    /// Returns true if every edge has the same weight in both directions
+    pub fn is_symmetric(&self) -> bool {
+        (0..self.nodes).all(|a| (a + 1..self.nodes).all(|b| self.get(a, b) == self.get(b, a)))
+    }
+}
+
+#[test]
+fn test_graph() {
+    let mut graph = Graph::new(3);
+
+    graph.set_both(0, 1, 5);
+    assert!(graph.get(1, 0) == Some(5) && graph.get(0, 2).is_none());
+    assert!(graph.is_symmetric());
+
+    graph.set(1, 2, 3);
+    assert!(!graph.is_symmetric());
+    assert!(graph.len() == 3 && !graph.is_empty());
+}
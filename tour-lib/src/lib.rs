@@ -0,0 +1,7 @@
+//! Best orderings of the nodes of a weighted graph
+
+pub mod graph;
+pub mod tour;
+
+pub use graph::Graph;
+pub use tour::{Goal, Shape, Tour, TourSearch};
@@ -0,0 +1,229 @@
+//! Searches for the best ordering of every node in a graph
+
+use crate::Graph;
+use std::ops::Add;
+
+/// Whether a tour returns to its starting node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// Visits every node once, starting and ending anywhere
+    Path,
+    /// Visits every node once and returns to the start
+    Cycle
+}
+
+/// Which tour is considered best
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Min,
+    Max
+}
+
+impl Goal {
+    /// Returns true if a weight is strictly better than another
+    pub fn is_better<W: Ord>(&self, weight: W, than: W) -> bool {
+        match self {
+            Goal::Min => weight < than,
+            Goal::Max => weight > than
+        }
+    }
+}
+
+/// An ordering of the nodes of a graph with its total weight
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour<W> {
+    pub order: Vec<usize>,
+    pub weight: W
+}
+
+/// Search over every tour of a graph. Cycles always start at node 0, and if
+/// the graph is symmetric each tour is only visited in one direction
+pub struct TourSearch<'a, W> {
+    graph: &'a Graph<W>,
+    shape: Shape,
+    goal: Goal
+}
+
+impl<'a, W> TourSearch<'a, W>
+where
+    W: Copy + Default + Ord + Add<Output = W>
+{
+    pub fn new(graph: &'a Graph<W>) -> Self {
+        Self {
+            graph,
+            shape: Shape::Path,
+            goal: Goal::Min
+        }
+    }
+
+    /// Sets whether tours are open paths or closed cycles (default path)
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets whether the lowest or highest weight tour is best (default min)
+    pub fn goal(mut self, goal: Goal) -> Self {
+        self.goal = goal;
+        self
+    }
+
+    /// Calls a function with the order and weight of each tour. Tours which
+    /// need an edge missing from the graph are skipped
+    pub fn for_each<F: FnMut(&[usize], W)>(&self, mut f: F) {
+        let nodes = self.graph.len();
+        let symmetric = self.graph.is_symmetric();
+
+        let starts = match self.shape {
+            Shape::Path => nodes,
+            Shape::Cycle => nodes.min(1)
+        };
+
+        let mut order = Vec::with_capacity(nodes);
+        let mut visited = vec![false; nodes];
+
+        for start in 0..starts {
+            order.push(start);
+            visited[start] = true;
+
+            self.walk(&mut order, &mut visited, W::default(), symmetric, &mut f);
+
+            visited[start] = false;
+            order.pop();
+        }
+    }
+
+    /// Returns the best tour, if there is one
+    pub fn best(&self) -> Option<Tour<W>> {
+        self.best_with(|_, _| {})
+    }
+
+    /// Returns the best tour, calling a function with each tour considered.
+    /// The first tour found wins a tie
+    pub fn best_with<F: FnMut(&[usize], W)>(&self, mut f: F) -> Option<Tour<W>> {
+        let mut best: Option<Tour<W>> = None;
+
+        self.for_each(|order, weight| {
+            f(order, weight);
+
+            if best.as_ref().is_none_or(|b| self.goal.is_better(weight, b.weight)) {
+                best = Some(Tour { order: order.to_vec(), weight });
+            }
+        });
+
+        best
+    }
+
+    fn walk<F: FnMut(&[usize], W)>(&self, order: &mut Vec<usize>, visited: &mut [bool], weight: W, symmetric: bool, f: &mut F) {
+        let nodes = self.graph.len();
+        let last = order[order.len() - 1];
+
+        if order.len() == nodes {
+            if symmetric && self.is_reversed(order) {
+                return
+            }
+
+            let weight = match self.shape {
+                Shape::Cycle if nodes > 1 => match self.graph.get(last, order[0]) {
+                    Some(w) => weight + w,
+                    None => return
+                }
+                _ => weight
+            };
+
+            f(order, weight);
+            return
+        }
+
+        for next in 0..nodes {
+            if visited[next] {
+                continue
+            }
+
+            let w = match self.graph.get(last, next) {
+                Some(w) => w,
+                None => continue
+            };
+
+            order.push(next);
+            visited[next] = true;
+
+            self.walk(order, visited, weight + w, symmetric, f);
+
+            visited[next] = false;
+            order.pop();
+        }
+    }
+
+    /// Returns true for the second direction of a tour which has the same
+    /// weight both ways round
+    fn is_reversed(&self, order: &[usize]) -> bool {
+        let last = order[order.len() - 1];
+
+        match self.shape {
+            Shape::Path => order.len() >= 2 && order[0] > last,
+            Shape::Cycle => order.len() >= 3 && order[1] > last
+        }
+    }
+}
+
+#[test]
+fn test_symmetric_tours() {
+    // London, Dublin, Belfast
+    let mut graph = Graph::new(3);
+    graph.set_both(0, 1, 464);
+    graph.set_both(0, 2, 518);
+    graph.set_both(1, 2, 141);
+
+    let mut count = 0;
+    TourSearch::new(&graph).for_each(|_, _| count += 1);
+    assert!(count == 3);
+
+    let shortest = TourSearch::new(&graph).best().unwrap();
+    assert!(shortest.weight == 605 && shortest.order == vec![0, 1, 2]);
+
+    let longest = TourSearch::new(&graph).goal(Goal::Max).best().unwrap();
+    assert!(longest.weight == 982 && longest.order == vec![1, 0, 2]);
+
+    let cycle = TourSearch::new(&graph).shape(Shape::Cycle).best().unwrap();
+    assert!(cycle.weight == 464 + 518 + 141 && cycle.order == vec![0, 1, 2]);
+}
+
+#[test]
+fn test_asymmetric_tours() {
+    let mut graph = Graph::new(3);
+
+    for a in 0..3 {
+        for b in 0..3 {
+            graph.set(a, b, 10);
+        }
+    }
+
+    graph.set(0, 1, 1);
+    graph.set(1, 2, 1);
+    graph.set(2, 0, 1);
+
+    let mut count = 0;
+    TourSearch::new(&graph).for_each(|_, _| count += 1);
+    assert!(count == 6);
+
+    let path = TourSearch::new(&graph).best().unwrap();
+    assert!(path.weight == 2 && path.order == vec![0, 1, 2]);
+
+    let cycle = TourSearch::new(&graph).shape(Shape::Cycle).best().unwrap();
+    assert!(cycle.weight == 3 && cycle.order == vec![0, 1, 2]);
+
+    let cycle = TourSearch::new(&graph).shape(Shape::Cycle).goal(Goal::Max).best().unwrap();
+    assert!(cycle.weight == 30 && cycle.order == vec![0, 2, 1]);
+}
+
+#[test]
+fn test_missing_edges() {
+    let mut graph = Graph::new(3);
+    graph.set_both(0, 1, 1);
+    graph.set_both(1, 2, 1);
+
+    assert!(TourSearch::new(&graph).best().unwrap().order == vec![0, 1, 2]);
+    assert!(TourSearch::new(&graph).shape(Shape::Cycle).best().is_none());
+    assert!(TourSearch::new(&Graph::<u32>::new(0)).best().is_none());
+}